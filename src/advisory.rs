@@ -0,0 +1,233 @@
+//! OSV Advisory Lookup
+//!
+//! Matches a pinned package name + version against known-vulnerable
+//! advisories from the OSV (Open Source Vulnerabilities) project. Supports
+//! an online mode that queries the public OSV API and an offline mode that
+//! consults a bundled/point-in-time snapshot so scans stay deterministic
+//! and air-gapped.
+
+use serde::Deserialize;
+
+use crate::models::Severity;
+
+/// A single matched advisory, normalized from either OSV source.
+#[derive(Debug, Clone)]
+pub struct OsvAdvisory {
+    pub id: String,
+    pub summary: String,
+    pub cvss_score: Option<f32>,
+}
+
+/// Map a CVSS base score (0.0-10.0) onto our internal `Severity` scale.
+/// Unscored advisories default to `Medium` rather than being dropped.
+pub fn severity_from_cvss(score: Option<f32>) -> Severity {
+    match score {
+        Some(s) if s >= 9.0 => Severity::Critical,
+        Some(s) if s >= 7.0 => Severity::High,
+        Some(s) if s >= 4.0 => Severity::Medium,
+        Some(_) => Severity::Low,
+        None => Severity::Medium,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvQuerySeverity {
+    #[serde(rename = "type")]
+    kind: String,
+    score: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvQueryVuln {
+    id: String,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    severity: Vec<OsvQuerySeverity>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvQueryResponse {
+    #[serde(default)]
+    vulns: Vec<OsvQueryVuln>,
+}
+
+/// Query the public OSV API for advisories affecting `package@version` in
+/// `ecosystem` (e.g. `"npm"`). Any network or parse failure yields an empty
+/// result rather than an error, since this is best-effort enrichment.
+pub fn query_online(ecosystem: &str, package: &str, version: &str) -> Vec<OsvAdvisory> {
+    let body = serde_json::json!({
+        "version": version,
+        "package": { "name": package, "ecosystem": ecosystem }
+    });
+
+    let response = match reqwest::blocking::Client::new()
+        .post("https://api.osv.dev/v1/query")
+        .json(&body)
+        .send()
+    {
+        Ok(r) => r,
+        Err(_) => return Vec::new(),
+    };
+
+    let parsed: OsvQueryResponse = match response.json() {
+        Ok(p) => p,
+        Err(_) => return Vec::new(),
+    };
+
+    parsed
+        .vulns
+        .into_iter()
+        .map(|v| {
+            let score = v
+                .severity
+                .iter()
+                .find(|s| s.kind.starts_with("CVSS_V3"))
+                .or_else(|| v.severity.first())
+                .and_then(|s| cvss_v3_base_score(&s.score));
+
+            OsvAdvisory {
+                id: v.id,
+                summary: v.summary,
+                cvss_score: score,
+            }
+        })
+        .collect()
+}
+
+/// Compute the CVSS v3.x base score from a vector string
+/// (e.g. `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`), per the CVSS v3.1
+/// base score formula. OSV's `severity[].score` carries this vector rather
+/// than a bare number, so a naive `.parse::<f32>()` on it always fails;
+/// this computes the number FIRST.org actually intends.
+///
+/// Only CVSS v3.0/3.1 vectors are supported - CVSS v2 vectors (no `CVSS:`
+/// prefix) or v4 vectors return `None` and fall back to the caller's
+/// unscored default rather than a wrong number.
+fn cvss_v3_base_score(vector: &str) -> Option<f32> {
+    if !vector.starts_with("CVSS:3.") {
+        return None;
+    }
+
+    let mut metrics = std::collections::HashMap::new();
+    for part in vector.split('/').skip(1) {
+        let mut kv = part.splitn(2, ':');
+        if let (Some(k), Some(v)) = (kv.next(), kv.next()) {
+            metrics.insert(k, v);
+        }
+    }
+
+    let av = match *metrics.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let ac = match *metrics.get("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let scope_changed = match *metrics.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let pr = match (*metrics.get("PR")?, scope_changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let ui = match *metrics.get("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let impact_metric = |key: &str| -> Option<f32> {
+        match *metrics.get(key)? {
+            "H" => Some(0.56),
+            "L" => Some(0.22),
+            "N" => Some(0.0),
+            _ => None,
+        }
+    };
+    let c = impact_metric("C")?;
+    let i = impact_metric("I")?;
+    let a = impact_metric("A")?;
+
+    let iss = 1.0 - ((1.0 - c) * (1.0 - i) * (1.0 - a));
+    let impact = if scope_changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powf(15.0)
+    } else {
+        6.42 * iss
+    };
+
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let raw = if scope_changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+
+    Some(cvss_roundup(raw.min(10.0)))
+}
+
+/// CVSS's "round up to one decimal place" - not plain float rounding, since
+/// e.g. 4.02 must round up to 4.1, not down to 4.0.
+fn cvss_roundup(value: f32) -> f32 {
+    let int_value = (value * 100_000.0).round() as i64;
+    if int_value % 10_000 == 0 {
+        int_value as f32 / 100_000.0
+    } else {
+        ((int_value / 10_000) + 1) as f32 / 10.0
+    }
+}
+
+/// A single entry from a bundled/point-in-time OSV snapshot.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OfflineOsvEntry {
+    pub ecosystem: String,
+    pub package: String,
+    pub version: String,
+    pub id: String,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub cvss_score: Option<f32>,
+}
+
+/// An offline OSV database: a flat list of known `package@version ->
+/// advisory` matches, loaded from a JSON file bundled with the scanner.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OfflineOsvDb {
+    #[serde(default)]
+    pub entries: Vec<OfflineOsvEntry>,
+}
+
+impl OfflineOsvDb {
+    pub fn from_file(path: &std::path::Path) -> Result<Self, String> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read OSV db: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse OSV db: {}", e))
+    }
+
+    pub fn lookup(&self, ecosystem: &str, package: &str, version: &str) -> Vec<OsvAdvisory> {
+        self.entries
+            .iter()
+            .filter(|e| e.ecosystem == ecosystem && e.package == package && e.version == version)
+            .map(|e| OsvAdvisory {
+                id: e.id.clone(),
+                summary: e.summary.clone(),
+                cvss_score: e.cvss_score,
+            })
+            .collect()
+    }
+}
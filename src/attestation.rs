@@ -0,0 +1,307 @@
+//! Report Attestation
+//!
+//! Signs a `ScanResult` as a compact JWS (JWT) so a report can be verified
+//! as authentic and unmodified after the fact - a cryptographically
+//! attestable artifact rather than a plain JSON blob anyone can edit.
+//!
+//! Uses Ed25519 (EdDSA) over the JSON-serialized payload: `iat`, an optional
+//! `sub` (the scanned host), and the full `ScanResult` as the `scanResult`
+//! claim. "Canonicalization" here just means serde's stable, insertion-order
+//! field emission - not full JCS (RFC 8785) - which is sufficient since both
+//! sign and verify go through the same serializer.
+//!
+//! Keys are loaded from PEM files holding the raw 32-byte Ed25519 seed/public
+//! key, base64-encoded between standard `-----BEGIN/END-----` markers -
+//! PKCS8-wrapped keys are not supported.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Finding, ScanResult};
+
+const ALG: &str = "EdDSA";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Header {
+    alg: String,
+    typ: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Claims {
+    iat: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<String>,
+    #[serde(rename = "scanResult")]
+    scan_result: ScanResult,
+}
+
+const B64URL_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn b64url_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(B64URL_ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(B64URL_ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        if chunk.len() > 1 {
+            out.push(B64URL_ALPHABET[(n >> 6 & 0x3f) as usize] as char);
+        }
+        if chunk.len() > 2 {
+            out.push(B64URL_ALPHABET[(n & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn b64url_decode(s: &str) -> Result<Vec<u8>, String> {
+    fn value(c: u8) -> Option<u8> {
+        match c {
+            b'A'..=b'Z' => Some(c - b'A'),
+            b'a'..=b'z' => Some(c - b'a' + 26),
+            b'0'..=b'9' => Some(c - b'0' + 52),
+            b'-' => Some(62),
+            b'_' => Some(63),
+            _ => None,
+        }
+    }
+    decode_base64(s.as_bytes(), value)
+}
+
+/// Standard (non-URL) base64 decoder, for the body of a PEM block.
+fn decode_base64_std(s: &str) -> Result<Vec<u8>, String> {
+    fn value(c: u8) -> Option<u8> {
+        match c {
+            b'A'..=b'Z' => Some(c - b'A'),
+            b'a'..=b'z' => Some(c - b'a' + 26),
+            b'0'..=b'9' => Some(c - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+    decode_base64(s.as_bytes(), value)
+}
+
+fn decode_base64(bytes: &[u8], value: fn(u8) -> Option<u8>) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(bytes.len() * 3 / 4);
+    let mut buf = 0u32;
+    let mut bits = 0u32;
+    for &b in bytes {
+        if b == b'=' {
+            break;
+        }
+        let v = value(b).ok_or("invalid base64 character")? as u32;
+        buf = (buf << 6) | v;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buf >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+fn strip_pem(pem: &str) -> Result<Vec<u8>, String> {
+    let body: String = pem.lines().filter(|line| !line.starts_with("-----")).collect();
+    decode_base64_std(body.trim())
+}
+
+fn load_signing_key(private_key_pem: &str) -> Result<SigningKey, String> {
+    let bytes = strip_pem(private_key_pem)?;
+    let seed: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+        format!(
+            "expected a 32-byte Ed25519 seed, got {} bytes (raw seed PEMs only, not PKCS8)",
+            bytes.len()
+        )
+    })?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn load_verifying_key(public_key_pem: &str) -> Result<VerifyingKey, String> {
+    let bytes = strip_pem(public_key_pem)?;
+    let key: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| format!("expected a 32-byte Ed25519 public key, got {} bytes", bytes.len()))?;
+    VerifyingKey::from_bytes(&key).map_err(|e| format!("invalid Ed25519 public key: {}", e))
+}
+
+/// Sign `result` into a compact JWS (`header.claims.signature`, each segment
+/// base64url-encoded) using the Ed25519 private key in `private_key_pem`.
+/// `iat` is the issued-at Unix timestamp (seconds); `host` becomes the `sub`
+/// claim if given.
+pub fn sign(result: &ScanResult, host: Option<&str>, iat: u64, private_key_pem: &str) -> Result<String, String> {
+    let signing_key = load_signing_key(private_key_pem)?;
+
+    let header = Header {
+        alg: ALG.to_string(),
+        typ: "JWT".to_string(),
+    };
+    let claims = Claims {
+        iat,
+        sub: host.map(String::from),
+        scan_result: result.clone(),
+    };
+
+    let header_b64 = b64url_encode(&serde_json::to_vec(&header).map_err(|e| e.to_string())?);
+    let claims_b64 = b64url_encode(&serde_json::to_vec(&claims).map_err(|e| e.to_string())?);
+    let signing_input = format!("{}.{}", header_b64, claims_b64);
+
+    let signature: Signature = signing_key.sign(signing_input.as_bytes());
+    let signature_b64 = b64url_encode(&signature.to_bytes());
+
+    Ok(format!("{}.{}", signing_input, signature_b64))
+}
+
+/// Verify a compact JWS produced by [`sign`] against the Ed25519 public key
+/// in `public_key_pem`, returning the embedded `ScanResult` only if the
+/// signature is valid.
+pub fn verify(jws: &str, public_key_pem: &str) -> Result<ScanResult, String> {
+    let mut parts = jws.split('.');
+    let (Some(header_b64), Some(claims_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err("malformed JWS: expected exactly 3 dot-separated segments".to_string());
+    };
+
+    let verifying_key = load_verifying_key(public_key_pem)?;
+
+    let signing_input = format!("{}.{}", header_b64, claims_b64);
+    let signature_bytes = b64url_decode(signature_b64)?;
+    let signature_bytes: [u8; 64] = signature_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| "malformed signature: expected 64 bytes".to_string())?;
+    let signature = Signature::from_bytes(&signature_bytes);
+
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .map_err(|_| "signature verification failed".to_string())?;
+
+    let claims_bytes = b64url_decode(claims_b64)?;
+    let claims: Claims = serde_json::from_slice(&claims_bytes).map_err(|e| format!("failed to parse claims: {}", e))?;
+    Ok(claims.scan_result)
+}
+
+// --- Findings-only report tokens -------------------------------------
+//
+// A narrower, more PASETO-v4-like sibling to the JWS pair above: instead of
+// attesting a whole `ScanResult`, this signs just the findings from one scan
+// run plus provenance (which scanner produced it, when, and a hash of the
+// config that was scanned). Token shape is `v4.public.<payload>.<sig>`,
+// each segment base64url-encoded - modeled on PASETO v4.public but not
+// spec-compliant (real PASETO packs payload and signature into a single
+// base64 segment; keeping them separate here matches the JWS segment style
+// above and keeps decoding simple).
+
+const REPORT_HEADER: &str = "v4.public";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReportPayload {
+    scanner: String,
+    iat: u64,
+    exp: u64,
+    #[serde(rename = "configHash")]
+    config_hash: String,
+    findings: Vec<Finding>,
+}
+
+/// Why [`verify_report`] rejected a token.
+#[derive(Debug)]
+pub enum VerifyError {
+    Malformed(String),
+    InvalidSignature,
+    Expired { exp: u64, now: u64 },
+}
+
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyError::Malformed(reason) => write!(f, "malformed report token: {}", reason),
+            VerifyError::InvalidSignature => write!(f, "report signature verification failed"),
+            VerifyError::Expired { exp, now } => write!(f, "report expired at {} (now {})", exp, now),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// Sign `findings` into a `v4.public`-style token (`v4.public.<payload>.<sig>`)
+/// using the Ed25519 private key in `private_key_pem`. `scanner` identifies
+/// the producing scanner (e.g. `"dino-aiss"`), `config_hash` should identify
+/// the exact config that was scanned (e.g. a SHA-256 hex digest), and
+/// `iat`/`exp` are Unix timestamps (seconds) bounding the report's validity.
+pub fn sign_report(
+    findings: &[Finding],
+    scanner: &str,
+    config_hash: &str,
+    iat: u64,
+    exp: u64,
+    private_key_pem: &str,
+) -> Result<String, String> {
+    let signing_key = load_signing_key(private_key_pem)?;
+
+    let payload = ReportPayload {
+        scanner: scanner.to_string(),
+        iat,
+        exp,
+        config_hash: config_hash.to_string(),
+        findings: findings.to_vec(),
+    };
+
+    let payload_b64 = b64url_encode(&serde_json::to_vec(&payload).map_err(|e| e.to_string())?);
+    let signing_input = format!("{}.{}", REPORT_HEADER, payload_b64);
+
+    let signature: Signature = signing_key.sign(signing_input.as_bytes());
+    let signature_b64 = b64url_encode(&signature.to_bytes());
+
+    Ok(format!("{}.{}", signing_input, signature_b64))
+}
+
+/// Verify a token produced by [`sign_report`] against the Ed25519 public key
+/// in `public_key_pem`, rejecting tampered signatures and tokens whose `exp`
+/// claim is at or before `now` (Unix seconds). Returns the embedded findings
+/// only if both checks pass.
+pub fn verify_report(token: &str, public_key_pem: &str, now: u64) -> Result<Vec<Finding>, VerifyError> {
+    let mut parts = token.split('.');
+    let (Some(v), Some(purpose), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(VerifyError::Malformed(
+            "expected exactly 4 dot-separated segments".to_string(),
+        ));
+    };
+
+    if format!("{}.{}", v, purpose) != REPORT_HEADER {
+        return Err(VerifyError::Malformed(format!("unsupported token header: {}.{}", v, purpose)));
+    }
+
+    let verifying_key = load_verifying_key(public_key_pem).map_err(VerifyError::Malformed)?;
+
+    let signing_input = format!("{}.{}", REPORT_HEADER, payload_b64);
+    let signature_bytes = b64url_decode(signature_b64).map_err(VerifyError::Malformed)?;
+    let signature_bytes: [u8; 64] = signature_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| VerifyError::Malformed("expected 64-byte signature".to_string()))?;
+    let signature = Signature::from_bytes(&signature_bytes);
+
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .map_err(|_| VerifyError::InvalidSignature)?;
+
+    let payload_bytes = b64url_decode(payload_b64).map_err(VerifyError::Malformed)?;
+    let payload: ReportPayload = serde_json::from_slice(&payload_bytes)
+        .map_err(|e| VerifyError::Malformed(format!("failed to parse payload: {}", e)))?;
+
+    if now >= payload.exp {
+        return Err(VerifyError::Expired { exp: payload.exp, now });
+    }
+
+    Ok(payload.findings)
+}
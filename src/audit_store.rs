@@ -0,0 +1,99 @@
+//! Plugin/Skill Trust & Exemption Store
+//!
+//! A cargo-vet-style audit file (e.g. `audits.toml`) recording third-party
+//! plugins/skills the operator has already reviewed, so `PluginScanner`
+//! doesn't re-flag a legitimately vetted source on every run.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Finding;
+
+/// A vetted source, optionally pinned to a specific version/hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub source: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    pub reviewer: String,
+    pub criteria: String,
+}
+
+/// A source exempted from scanning outright (no review performed, but
+/// accepted as a known risk).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exemption {
+    pub source: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditStore {
+    #[serde(default)]
+    pub audits: Vec<AuditEntry>,
+    #[serde(default)]
+    pub exemptions: Vec<Exemption>,
+}
+
+impl AuditStore {
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read audit store: {}", e))?;
+        toml::from_str(&content).map_err(|e| format!("Failed to parse audit store: {}", e))
+    }
+
+    /// Is `source` (optionally pinned at `version`) already vetted or
+    /// exempted, so the scanner should downgrade/drop findings about it?
+    pub fn is_trusted(&self, source: &str, version: Option<&str>) -> bool {
+        if self.exemptions.iter().any(|e| e.source == source) {
+            return true;
+        }
+
+        self.audits.iter().any(|audit| {
+            audit.source == source
+                && match (&audit.version, version) {
+                    (Some(audited), Some(seen)) => audited == seen,
+                    (None, _) => true,
+                    (Some(_), None) => false,
+                }
+        })
+    }
+
+    /// Merge another store's audits/exemptions into this one, so a project
+    /// can aggregate trust stores published by upstream registries.
+    pub fn import(&mut self, other: AuditStore) {
+        self.audits.extend(other.audits);
+        self.exemptions.extend(other.exemptions);
+    }
+
+    /// Turn currently-unresolved trust findings into proposed audit entries
+    /// an operator can review before committing them to the store.
+    /// A `Finding` has no field for the plugin's or skill's own source, so each
+    /// proposed entry's `source` is the finding's `config_path` (e.g.
+    /// `plugins.installed[].source`); such an entry does not match anything in
+    /// [`AuditStore::is_trusted`] until that field is replaced with the real
+    /// source.
+    pub fn suggest(findings: &[Finding]) -> Vec<AuditEntry> {
+        findings
+            .iter()
+            .filter(|f| {
+                matches!(
+                    f.id.as_str(),
+                    "plugins.untrusted_source" | "plugins.unpinned_version" | "skills.untrusted_source"
+                )
+            })
+            .map(|f| AuditEntry {
+                source: f.config_path.clone(),
+                version: None,
+                sha256: None,
+                reviewer: "unreviewed".to_string(),
+                criteria: "safe-to-run".to_string(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,74 @@
+//! Findings Baseline / Waiver File
+//!
+//! Modeled on supply-chain audit files (e.g. `cargo vet`'s audits): a TOML
+//! document listing finding ids the operator has already reviewed and
+//! accepted, each with an optional reason and expiry date. Waived findings
+//! are excluded from the health score and exit-code calculation but are
+//! still shown (dimmed) in the reports so stale waivers stay visible.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::ScanResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Waiver {
+    pub reason: Option<String>,
+    /// ISO 8601 date (`YYYY-MM-DD`). Waivers past this date are ignored and
+    /// the finding is re-raised as active.
+    pub expires: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default)]
+    pub waivers: HashMap<String, Waiver>,
+}
+
+impl Baseline {
+    /// Load a baseline file (TOML).
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Err(format!("Baseline file not found: {}", path.display()));
+        }
+
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read baseline: {}", e))?;
+
+        toml::from_str(&content).map_err(|e| format!("Failed to parse baseline: {}", e))
+    }
+
+    /// Is `finding_id` waived as of `today` (`YYYY-MM-DD`)? An expired
+    /// waiver (`expires` < `today`) does not count as waived.
+    pub fn is_waived(&self, finding_id: &str, today: &str) -> bool {
+        match self.waivers.get(finding_id) {
+            Some(waiver) => match &waiver.expires {
+                Some(expires) => expires.as_str() >= today,
+                None => true,
+            },
+            None => false,
+        }
+    }
+}
+
+/// Move every finding matched (and not expired) in `baseline` out of
+/// `result.findings` and into `result.waived`, then recompute the health
+/// score from the remaining active findings only.
+pub fn apply(result: &mut ScanResult, baseline: &Baseline, today: &str) {
+    let findings = std::mem::take(&mut result.findings);
+    let (active, waived): (Vec<_>, Vec<_>) = findings
+        .into_iter()
+        .partition(|f| !baseline.is_waived(&f.id, today));
+
+    result.findings = active;
+    result.waived.extend(waived);
+
+    let mut score = 100;
+    for finding in &result.findings {
+        score += finding.severity.score();
+    }
+    result.health_score = score.clamp(0, 100);
+}
@@ -5,6 +5,25 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+/// Gateway transport security (`gateway.tls`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TlsConfig {
+    pub enabled: Option<bool>,
+    pub cert_path: Option<String>,
+    pub key_path: Option<String>,
+    pub min_version: Option<String>,
+}
+
+/// Hardening headers served by the gateway/Control UI (`gateway.headers`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeadersConfig {
+    pub content_security_policy: Option<String>,
+    pub x_content_type_options: Option<String>,
+    pub referrer_policy: Option<String>,
+    pub x_frame_options: Option<String>,
+    pub permissions_policy: Option<String>,
+}
+
 /// Gateway configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GatewayConfig {
@@ -18,6 +37,21 @@ pub struct GatewayConfig {
     pub control_ui_origins: Option<Vec<String>>,
     pub trusted_proxies: Option<Vec<String>>,
     pub http_no_auth: Option<bool>,
+    pub tls: TlsConfig,
+    pub headers: HeadersConfig,
+}
+
+/// A single Tauri-style capability grant/revocation from `tools.capabilities`.
+///
+/// `tool` names the tool the entry applies to, either exactly (`exec`) or as
+/// a glob (`fs_*`). `scope` narrows what the permission covers: path
+/// prefixes for `fs_*`, binary names for `exec`, domains for `webFetch`. An
+/// absent or empty scope means the permission applies globally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Capability {
+    pub permission: String,
+    pub tool: String,
+    pub scope: Option<Vec<String>>,
 }
 
 /// Tools configuration
@@ -33,6 +67,8 @@ pub struct ToolsConfig {
     pub fs_workspace_only: Option<bool>,
     pub web_fetch_ssrf_policy: Option<String>,
     pub web_search_ssrf_policy: Option<String>,
+    pub confirm_filter: Option<String>,
+    pub capabilities: Option<Vec<Capability>>,
 }
 
 /// Sandbox configuration
@@ -146,6 +182,36 @@ impl OpenClawConfig {
                     .get("http")
                     .and_then(|v| v.get("noAuth"))
                     .and_then(|v| v.as_bool()),
+                tls: gw
+                    .get("tls")
+                    .and_then(|v| v.as_object())
+                    .map(|t| TlsConfig {
+                        enabled: t.get("enabled").and_then(|v| v.as_bool()),
+                        cert_path: t.get("certPath").and_then(|v| v.as_str()).map(String::from),
+                        key_path: t.get("keyPath").and_then(|v| v.as_str()).map(String::from),
+                        min_version: t.get("minVersion").and_then(|v| v.as_str()).map(String::from),
+                    })
+                    .unwrap_or_default(),
+                headers: gw
+                    .get("headers")
+                    .and_then(|v| v.as_object())
+                    .map(|h| HeadersConfig {
+                        content_security_policy: h
+                            .get("contentSecurityPolicy")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                        x_content_type_options: h
+                            .get("xContentTypeOptions")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                        referrer_policy: h.get("referrerPolicy").and_then(|v| v.as_str()).map(String::from),
+                        x_frame_options: h.get("xFrameOptions").and_then(|v| v.as_str()).map(String::from),
+                        permissions_policy: h
+                            .get("permissionsPolicy")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                    })
+                    .unwrap_or_default(),
             };
         }
 
@@ -200,6 +266,31 @@ impl OpenClawConfig {
                     .and_then(|v| v.get("ssrfPolicy"))
                     .and_then(|v| v.as_str())
                     .map(String::from),
+                confirm_filter: tl
+                    .get("confirm")
+                    .and_then(|v| v.get("pattern"))
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                capabilities: tl.get("capabilities").and_then(|v| v.as_array()).map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_object())
+                        .map(|obj| Capability {
+                            permission: obj
+                                .get("permission")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("allow")
+                                .to_string(),
+                            tool: obj
+                                .get("tool")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                            scope: obj.get("scope").and_then(|v| v.as_array()).map(|s| {
+                                s.iter().filter_map(|v| v.as_str().map(String::from)).collect()
+                            }),
+                        })
+                        .collect()
+                }),
             };
         }
 
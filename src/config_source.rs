@@ -0,0 +1,114 @@
+//! Pluggable Config Sources
+//!
+//! `apply_fixes` and the scanners assume a single on-disk JSON/YAML file.
+//! `ConfigSource` abstracts "where config lives" behind `load`/`store` so the
+//! same scan+remediate pipeline can run against a config copied onto each
+//! host ([`LocalFileSource`]) or a centrally managed store shared by many
+//! gateway nodes ([`RemoteConfigSource`]). Remediation then writes back
+//! through whichever provider is selected, and "backups" become whatever the
+//! provider's own history looks like rather than always a `.bak` file.
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::OpenClawConfig;
+
+/// Where a scan's config comes from, and where a fix gets written back to.
+pub trait ConfigSource {
+    /// Load and parse the current configuration.
+    fn load(&self) -> Result<OpenClawConfig, String>;
+
+    /// Persist `value` as the new configuration.
+    fn store(&self, value: &serde_json::Value) -> Result<(), String>;
+}
+
+/// The existing local-file flow: config lives in a JSON/YAML file on disk,
+/// and a `.bak` copy of the previous contents is written before each store -
+/// the same backup behavior as [`crate::fixer::apply_fixes`].
+pub struct LocalFileSource {
+    pub path: PathBuf,
+}
+
+impl LocalFileSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl ConfigSource for LocalFileSource {
+    fn load(&self) -> Result<OpenClawConfig, String> {
+        OpenClawConfig::from_file(&self.path)
+    }
+
+    fn store(&self, value: &serde_json::Value) -> Result<(), String> {
+        if let Ok(existing) = fs::read_to_string(&self.path) {
+            let backup_path = format!("{}.bak", self.path.display());
+            fs::write(&backup_path, existing).map_err(|e| format!("Failed to write backup: {}", e))?;
+        }
+
+        let serialized = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+        fs::write(&self.path, serialized).map_err(|e| format!("Failed to write config: {}", e))
+    }
+}
+
+/// A centrally managed config store reachable over HTTP: `GET {base_url}`
+/// returns the current config as JSON, `PUT {base_url}` stores a new
+/// version. Intended for teams running many gateway nodes off one
+/// authoritative config rather than files copied onto each host - the store
+/// is expected to keep its own version history, so `store` does not also
+/// maintain a local `.bak`.
+pub struct RemoteConfigSource {
+    pub base_url: String,
+    pub auth_token: Option<String>,
+}
+
+impl RemoteConfigSource {
+    pub fn new(base_url: impl Into<String>, auth_token: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            auth_token,
+        }
+    }
+
+    fn authed(&self, builder: reqwest::blocking::RequestBuilder) -> reqwest::blocking::RequestBuilder {
+        match &self.auth_token {
+            Some(token) => builder.bearer_auth(token),
+            None => builder,
+        }
+    }
+}
+
+impl ConfigSource for RemoteConfigSource {
+    fn load(&self) -> Result<OpenClawConfig, String> {
+        let client = reqwest::blocking::Client::new();
+        let response = self
+            .authed(client.get(&self.base_url))
+            .send()
+            .map_err(|e| format!("Failed to fetch config: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Config store returned {}", response.status()));
+        }
+
+        let data: serde_json::Value = response
+            .json()
+            .map_err(|e| format!("Failed to parse config response: {}", e))?;
+
+        OpenClawConfig::from_dict(data)
+    }
+
+    fn store(&self, value: &serde_json::Value) -> Result<(), String> {
+        let client = reqwest::blocking::Client::new();
+        let response = self
+            .authed(client.put(&self.base_url))
+            .json(value)
+            .send()
+            .map_err(|e| format!("Failed to store config: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Config store rejected write: {}", response.status()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+//! Finding Correlation Engine
+//!
+//! Individual scanners emit isolated findings, but the dangerous states are
+//! combinations of them: sandbox mode off, an ungated control-plane tool,
+//! and an unauthenticated LAN-bound gateway together mean remote code
+//! execution, not three mediocre misconfigurations. This runs as a post-scan
+//! pass that looks for a predefined set of co-occurring finding ids and, on
+//! a full match, appends one synthesized, higher-severity "attack chain"
+//! finding describing the combined risk - turning a flat list into
+//! prioritized, actionable incident groupings.
+
+use std::collections::HashSet;
+
+use crate::models::{Finding, ScanResult, Severity};
+
+/// A named cluster of finding ids that together represent a more severe,
+/// combined risk than any member on its own.
+pub struct ChainRule {
+    pub chain_id: &'static str,
+    pub title: &'static str,
+    /// Finding ids that must ALL be present in a scan for this chain to fire.
+    pub member_ids: &'static [&'static str],
+    pub severity: Severity,
+    pub description: &'static str,
+    pub impact: &'static str,
+    pub remediation: &'static str,
+}
+
+/// Built-in chain rules for well-known dangerous combinations.
+pub fn default_rules() -> Vec<ChainRule> {
+    vec![
+        ChainRule {
+            chain_id: "chain.remote_rce",
+            title: "Remote Code Execution Chain",
+            member_ids: &[
+                "sandbox.mode_off",
+                "control_plane.gateway_not_denied",
+                "gateway.lan_no_auth",
+            ],
+            severity: Severity::Critical,
+            description: "Sandbox is disabled, the gateway tool is not denied at the control plane, and the LAN-bound gateway accepts unauthenticated connections - together these let any network peer reach the gateway tool and run commands directly on the host.",
+            impact: "An unauthenticated network peer can achieve remote code execution on the host",
+            remediation: "Enable sandbox mode, add 'gateway' to the control-plane deny list, and require a strong gateway.auth.token",
+        },
+        ChainRule {
+            chain_id: "chain.token_exfil",
+            title: "Credential Exfiltration Chain",
+            member_ids: &["gateway.low_entropy_token", "credentials.token_in_config"],
+            severity: Severity::High,
+            description: "The gateway token is both low-entropy and stored in plaintext config - a leaked config file hands an attacker a usable, guessable gateway credential, not just a reference to one.",
+            impact: "Config disclosure directly yields a working gateway credential",
+            remediation: "Rotate to a strong (32+ char) token and move it out of config into a secrets manager or environment variable",
+        },
+    ]
+}
+
+/// Append a synthesized chain finding for every rule in `rules` whose full
+/// `member_ids` set is present in `result.findings`. Member findings are
+/// left untouched - the chain finding is appended directly to `findings`
+/// rather than through [`ScanResult::add_finding`], so its severity is not
+/// layered on top of the health-score penalty its members already paid.
+pub fn apply(result: &mut ScanResult, rules: &[ChainRule]) {
+    let present_ids: HashSet<&str> = result.findings.iter().map(|f| f.id.as_str()).collect();
+
+    for rule in rules {
+        if rule.member_ids.iter().all(|id| present_ids.contains(id)) {
+            result.findings.push(Finding::new(
+                rule.chain_id,
+                "correlation",
+                rule.severity,
+                rule.title,
+                rule.description,
+                rule.impact,
+                rule.remediation,
+                "attack_chain",
+            ));
+        }
+    }
+}
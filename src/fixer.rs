@@ -5,6 +5,8 @@
 use std::fs;
 use std::path::Path;
 
+use crate::config_source::ConfigSource;
+
 /// Represents a fix to apply to the config
 #[derive(Debug, Clone)]
 pub struct ConfigFix {
@@ -86,6 +88,40 @@ pub fn generate_fixes(findings: &[crate::models::Finding]) -> Vec<ConfigFix> {
                 description: "Disable Tailscale Funnel".to_string(),
             }),
             
+            // Headers fixes
+            "headers.missing_csp" => Some(ConfigFix {
+                path: "gateway.headers".to_string(),
+                key: "contentSecurityPolicy".to_string(),
+                value: serde_json::Value::String("default-src 'self'".to_string()),
+                description: "Set a restrictive default Content-Security-Policy".to_string(),
+            }),
+            "headers.missing_nosniff" => Some(ConfigFix {
+                path: "gateway.headers".to_string(),
+                key: "xContentTypeOptions".to_string(),
+                value: serde_json::Value::String("nosniff".to_string()),
+                description: "Set X-Content-Type-Options to nosniff".to_string(),
+            }),
+            "headers.missing_referrer_policy" => Some(ConfigFix {
+                path: "gateway.headers".to_string(),
+                key: "referrerPolicy".to_string(),
+                value: serde_json::Value::String("same-origin".to_string()),
+                description: "Set a same-origin Referrer-Policy".to_string(),
+            }),
+            "headers.missing_frame_protection" => Some(ConfigFix {
+                path: "gateway.headers".to_string(),
+                key: "xFrameOptions".to_string(),
+                value: serde_json::Value::String("DENY".to_string()),
+                description: "Disallow framing with X-Frame-Options: DENY".to_string(),
+            }),
+            "headers.permissive_permissions_policy" => Some(ConfigFix {
+                path: "gateway.headers".to_string(),
+                key: "permissionsPolicy".to_string(),
+                value: serde_json::Value::String(
+                    "camera=(), microphone=(), geolocation=(), usb=()".to_string(),
+                ),
+                description: "Disable camera/microphone/geolocation/USB in Permissions-Policy".to_string(),
+            }),
+
             // Session fixes
             "session.dm_scope_main_multi_channel" | "session.dm_scope_default" => Some(ConfigFix {
                 path: "session".to_string(),
@@ -144,36 +180,121 @@ pub fn apply_fixes(config_path: &str, fixes: &[ConfigFix], dry_run: bool) -> Res
     // Write fixed config
     fs::write(path, &result)
         .map_err(|e| format!("Failed to write config: {}", e))?;
-    
-    Ok(format!("Applied fixes. Backup saved to: {}", backup_path))
+
+    if let Err(e) = verify_fixes(config_path, fixes) {
+        return Ok(format!(
+            "Applied fixes, but verification failed: {}. Backup saved to: {}",
+            e, backup_path
+        ));
+    }
+
+    Ok(format!("Applied and verified fixes. Backup saved to: {}", backup_path))
 }
 
+/// Like [`apply_fixes`], but against a pluggable [`ConfigSource`] instead of
+/// a path on disk - `source.store` takes over what a "backup" means (a
+/// `.bak` file for [`crate::config_source::LocalFileSource`], a new
+/// version for a remote store), so this lets the same fix logic run against
+/// a centrally managed configuration shared by many gateway nodes.
+pub fn apply_fixes_via(source: &dyn ConfigSource, fixes: &[ConfigFix]) -> Result<String, String> {
+    let config = source.load()?;
+    let mut value = config.raw;
+
+    for fix in fixes {
+        apply_fix_to_value(&mut value, &fix.path, &fix.key, fix.value.clone());
+    }
+
+    source.store(&value)?;
+
+    Ok(format!("Applied {} fix(es) via config source", fixes.len()))
+}
+
+/// Walk `path.split('.')` from the root of `config`, creating any missing
+/// intermediate objects (or replacing a non-object node in the way), then
+/// insert `key`/`value` into the object at the end of that path.
 fn apply_fix_to_value(config: &mut serde_json::Value, path: &str, key: &str, value: serde_json::Value) {
-    let parts: Vec<&str> = path.split('.').collect();
-    
-    // Navigate to the right location using index-based access
-    let mut target_idx = parts.len();
-    
-    // Create nested objects if needed
-    for (i, part) in parts.iter().enumerate() {
-        if i + 1 < parts.len() {
-            // Ensure the path exists
-            if config.get(*part).is_none() {
-                if let Some(obj) = config.as_object_mut() {
+    let mut target = config;
+
+    for part in path.split('.') {
+        if !target.get(part).is_some_and(|v| v.is_object()) {
+            match target.as_object_mut() {
+                Some(obj) => {
                     obj.insert(part.to_string(), serde_json::Value::Object(serde_json::Map::new()));
                 }
+                None => return,
             }
-        } else {
-            target_idx = i;
         }
+
+        target = match target.get_mut(part) {
+            Some(v) => v,
+            None => return,
+        };
     }
-    
-    // Apply the final fix
-    if let Some(obj) = config.as_object_mut() {
+
+    if let Some(obj) = target.as_object_mut() {
         obj.insert(key.to_string(), value);
     }
 }
 
+/// Re-read `config_path` and confirm every fix in `fixes` landed at its
+/// full `path.key`, so callers don't just trust that the write succeeded.
+pub fn verify_fixes(config_path: &str, fixes: &[ConfigFix]) -> Result<(), String> {
+    let content = fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read config: {}", e))?;
+    let config: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse config: {}", e))?;
+
+    for fix in fixes {
+        let mut node = &config;
+        for part in fix.path.split('.') {
+            node = match node.get(part) {
+                Some(v) => v,
+                None => return Err(format!("{}.{} is missing", fix.path, fix.key)),
+            };
+        }
+
+        match node.get(&fix.key) {
+            Some(actual) if *actual == fix.value => {}
+            Some(actual) => {
+                return Err(format!(
+                    "{}.{} is {} (expected {})",
+                    fix.path, fix.key, actual, fix.value
+                ))
+            }
+            None => return Err(format!("{}.{} is missing", fix.path, fix.key)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Restore `config_path` from its most recent `.bak` backup, atomically
+/// (write to a temp file then rename over the target) so a crash mid-restore
+/// can't leave the config half-written.
+pub fn restore_config(config_path: &str) -> Result<String, String> {
+    let backup_path = format!("{}.bak", config_path);
+    let backup = Path::new(&backup_path);
+
+    if !backup.exists() {
+        return Err(format!("No backup found at: {}", backup_path));
+    }
+
+    let content = fs::read_to_string(backup)
+        .map_err(|e| format!("Failed to read backup: {}", e))?;
+
+    // Refuse to restore a corrupt backup over a (possibly working) config.
+    serde_json::from_str::<serde_json::Value>(&content)
+        .map_err(|e| format!("Backup is not valid JSON, refusing to restore: {}", e))?;
+
+    let tmp_path = format!("{}.restore.tmp", config_path);
+    fs::write(&tmp_path, &content)
+        .map_err(|e| format!("Failed to write temp file: {}", e))?;
+    fs::rename(&tmp_path, config_path)
+        .map_err(|e| format!("Failed to restore config: {}", e))?;
+
+    Ok(format!("Restored {} from backup: {}", config_path, backup_path))
+}
+
 /// Preview what fixes would do without applying
 pub fn preview_fixes(findings: &[crate::models::Finding]) -> String {
     let fixes = generate_fixes(findings);
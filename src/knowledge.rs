@@ -5,7 +5,84 @@
 //! Contains CVE data and mitigation mappings
 
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Parse a dot-separated version into numeric components. Missing trailing
+/// components are treated as 0 by [`compare_components`], so `2026.2` and
+/// `2026.2.0` compare equal. Returns `None` for non-numeric input (e.g. the
+/// `various`/`<XXXXX` sentinels some knowledge-base entries use).
+fn parse_components(version: &str) -> Option<Vec<u32>> {
+    let version = version.trim();
+    if version.is_empty() {
+        return None;
+    }
+    version.split('.').map(|part| part.parse::<u32>().ok()).collect()
+}
+
+fn compare_components(a: &[u32], b: &[u32]) -> Ordering {
+    for i in 0..a.len().max(b.len()) {
+        let ord = a.get(i).copied().unwrap_or(0).cmp(&b.get(i).copied().unwrap_or(0));
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ConstraintOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+/// A single `<op><version>` constraint, e.g. `<2026.2.14` or `>=2026.2.0`.
+/// An `affected_versions` field is a comma-joined list of these that must
+/// *all* hold (`>=2026.2.0,<2026.2.14` is a half-open range).
+struct Constraint {
+    op: ConstraintOp,
+    version: Vec<u32>,
+}
+
+impl Constraint {
+    fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+        let (op, rest) = if let Some(rest) = raw.strip_prefix("<=") {
+            (ConstraintOp::Le, rest)
+        } else if let Some(rest) = raw.strip_prefix(">=") {
+            (ConstraintOp::Ge, rest)
+        } else if let Some(rest) = raw.strip_prefix('<') {
+            (ConstraintOp::Lt, rest)
+        } else if let Some(rest) = raw.strip_prefix('>') {
+            (ConstraintOp::Gt, rest)
+        } else if let Some(rest) = raw.strip_prefix('=') {
+            (ConstraintOp::Eq, rest)
+        } else {
+            (ConstraintOp::Eq, raw)
+        };
+
+        Some(Self {
+            op,
+            version: parse_components(rest)?,
+        })
+    }
+
+    fn matches(&self, version: &[u32]) -> bool {
+        let ord = compare_components(version, &self.version);
+        match self.op {
+            ConstraintOp::Lt => ord == Ordering::Less,
+            ConstraintOp::Le => ord != Ordering::Greater,
+            ConstraintOp::Gt => ord == Ordering::Greater,
+            ConstraintOp::Ge => ord != Ordering::Less,
+            ConstraintOp::Eq => ord == Ordering::Equal,
+        }
+    }
+}
 
 /// A CVE entry in the knowledge base
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,16 +209,54 @@ impl KnowledgeBase {
         self.cves.get(cve).map(|e| e.mitigation.as_str())
     }
 
-    /// Check if a version is affected
-    #[allow(dead_code)]
-    pub fn is_affected(&self, cve: &str, _version: &str) -> bool {
-        if let Some(entry) = self.cves.get(cve) {
-            if let Some(_affect_ver) = entry.affected_versions.strip_prefix("<") {
-                // This is a simplified check
-                return true;
+    /// Check if a version is affected by `cve`. `affected_versions` is a
+    /// comma-joined list of constraints (`<`, `<=`, `>`, `>=`, `=`) that must
+    /// all hold; an unparseable constraint (e.g. `various`, `<XXXXX`) makes
+    /// the whole entry non-matching rather than a false positive.
+    pub fn is_affected(&self, cve: &str, version: &str) -> bool {
+        let Some(entry) = self.cves.get(cve) else {
+            return false;
+        };
+        let Some(running) = parse_components(version) else {
+            return false;
+        };
+
+        entry
+            .affected_versions
+            .split(',')
+            .all(|raw| Constraint::parse(raw).is_some_and(|c| c.matches(&running)))
+    }
+
+    /// Load a knowledge-base pack (JSON or TOML) from `path`, shaped like
+    /// `KnowledgeBase` itself: `cves`/`patterns` maps.
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read knowledge base {}: {}", path.display(), e))?;
+
+        serde_json::from_str(&content)
+            .or_else(|_| toml::from_str(&content))
+            .map_err(|e| format!("Failed to parse knowledge base {}: {}", path.display(), e))
+    }
+
+    /// Merge `other` into `self`; `other`'s entries win on key collision.
+    /// Used to layer operator-supplied CVE/pattern packs on top of the
+    /// built-in defaults.
+    pub fn merge(&mut self, other: KnowledgeBase) {
+        self.cves.extend(other.cves);
+        self.patterns.extend(other.patterns);
+    }
+
+    /// Resolve a knowledge base as built-in defaults -> `system_path` ->
+    /// `user_path`, each later source overriding earlier ones by key.
+    /// Either path may be omitted or not exist; both are simply skipped.
+    pub fn load(system_path: Option<&Path>, user_path: Option<&Path>) -> Result<Self, String> {
+        let mut kb = KnowledgeBase::default();
+        for path in [system_path, user_path].into_iter().flatten() {
+            if path.exists() {
+                kb.merge(KnowledgeBase::from_path(path)?);
             }
         }
-        false
+        Ok(kb)
     }
 }
 
@@ -150,3 +265,61 @@ impl KnowledgeBase {
 pub fn get_knowledge_base() -> KnowledgeBase {
     KnowledgeBase::default()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_with_missing_patch_is_affected() {
+        let kb = KnowledgeBase::default();
+        assert!(kb.is_affected("CVE-2026-26322", "2026.2.13"));
+        assert!(kb.is_affected("CVE-2026-26322", "2026.2"));
+    }
+
+    #[test]
+    fn patched_version_is_not_affected() {
+        let kb = KnowledgeBase::default();
+        assert!(!kb.is_affected("CVE-2026-26322", "2026.2.14"));
+        assert!(!kb.is_affected("CVE-2026-26322", "2026.3.0"));
+    }
+
+    #[test]
+    fn sentinel_ranges_never_match() {
+        let kb = KnowledgeBase::default();
+        assert!(!kb.is_affected("CVE-2025-XXXXX", "2026.2.0"));
+    }
+
+    #[test]
+    fn comma_joined_range_requires_all_constraints() {
+        let constraint_lo = Constraint::parse(">=2026.2.0").unwrap();
+        let constraint_hi = Constraint::parse("<2026.2.14").unwrap();
+        let in_range = parse_components("2026.2.9").unwrap();
+        let below_range = parse_components("2026.1.9").unwrap();
+
+        assert!(constraint_lo.matches(&in_range) && constraint_hi.matches(&in_range));
+        assert!(!constraint_lo.matches(&below_range));
+    }
+
+    #[test]
+    fn merge_overrides_built_in_entries_by_key() {
+        let mut kb = KnowledgeBase::default();
+        let mut pack = KnowledgeBase {
+            cves: HashMap::new(),
+            patterns: HashMap::new(),
+        };
+        pack.cves.insert(
+            "CVE-2026-26322".to_string(),
+            CveEntry {
+                title: "Overridden".to_string(),
+                severity: "critical".to_string(),
+                description: "custom pack override".to_string(),
+                mitigation: "custom mitigation".to_string(),
+                affected_versions: "<2026.2.14".to_string(),
+            },
+        );
+
+        kb.merge(pack);
+        assert_eq!(kb.cves.get("CVE-2026-26322").unwrap().title, "Overridden");
+    }
+}
@@ -1,14 +1,24 @@
 //! Dino-AISS Library
 
+pub mod advisory;
+pub mod attestation;
+pub mod audit_store;
+pub mod baseline;
 pub mod config;
+pub mod config_source;
+pub mod correlation;
 pub mod fixer;
 pub mod knowledge;
 pub mod models;
+pub mod path_guard;
+pub mod policy;
+pub mod report;
 pub mod scanner;
+pub mod telemetry;
 
 pub use config::OpenClawConfig;
 pub use models::{Finding, ScanResult, Severity};
 pub use scanner::{
-    get_all_scanners, CredentialsScanner, GatewayScanner, PluginScanner, SandboxScanner, Scanner,
-    ToolsScanner,
+    get_all_scanners, CredentialsScanner, GatewayScanner, PluginScanner, RedosScanner,
+    SandboxScanner, SchemaScanner, Scanner, SsrfScanner, ToolsScanner,
 };
@@ -5,19 +5,32 @@
 
 mod models;
 mod config;
+mod config_source;
+mod advisory;
+mod attestation;
+mod audit_store;
 mod scanner;
 mod knowledge;
 mod fixer;
+mod baseline;
+mod correlation;
+mod path_guard;
+mod policy;
+mod report;
+mod telemetry;
 
 use std::path::Path;
 use std::time::Instant;
+use chrono::Local;
 use clap::{Parser, ValueEnum};
 use colored::*;
 
+use crate::baseline::Baseline;
 use crate::config::OpenClawConfig;
 use crate::models::{ScanResult, Severity};
 use crate::scanner::get_all_scanners;
 use crate::fixer::{generate_fixes, apply_fixes, preview_fixes};
+use crate::telemetry::{Telemetry, TelemetryConfig};
 
 #[derive(Parser, Debug)]
 #[command(name = "dino-aiss")]
@@ -63,6 +76,52 @@ struct Args {
     /// Generate upgrade guide
     #[arg(long)]
     upgrade_guide: bool,
+
+    /// Path to a baseline/waiver file (TOML) of accepted findings
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// Fail with a nonzero exit code if any finding at or above this
+    /// severity is present (shorthand for a zero-tolerance policy)
+    #[arg(long, value_enum)]
+    fail_on: Option<FailOnSeverity>,
+
+    /// Path to a severity-budget policy file (TOML) for CI gating
+    #[arg(long)]
+    policy: Option<String>,
+
+    /// OTLP endpoint to export scan traces/metrics to (enables telemetry)
+    #[arg(long)]
+    otel_endpoint: Option<String>,
+
+    /// Path to a system-level knowledge-base pack (JSON/TOML) of CVE/pattern
+    /// overrides, layered over the built-in defaults
+    #[arg(long)]
+    kb_system: Option<String>,
+
+    /// Path to a user-level knowledge-base pack (JSON/TOML), layered over
+    /// the built-ins and any --kb-system pack
+    #[arg(long)]
+    kb_user: Option<String>,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+enum FailOnSeverity {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+impl FailOnSeverity {
+    fn to_severity(self) -> Severity {
+        match self {
+            FailOnSeverity::Critical => Severity::Critical,
+            FailOnSeverity::High => Severity::High,
+            FailOnSeverity::Medium => Severity::Medium,
+            FailOnSeverity::Low => Severity::Low,
+        }
+    }
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -72,12 +131,36 @@ enum SeverityFilter {
     All,
 }
 
+/// An OpenClaw `YYYY.M.PATCH` version, ordered numerically component-by-
+/// component so e.g. `2026.2.9 < 2026.2.14` (a plain string compare gets
+/// this wrong).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Version(u32, u32, u32);
+
+impl Version {
+    fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next()?.parse().ok()?;
+        Some(Version(major, minor, patch))
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
 #[derive(Debug, Clone, ValueEnum)]
 enum OutputFormat {
     Console,
     Json,
     Markdown,
     Html,
+    Cyclonedx,
+    Sarif,
 }
 
 fn run_scan(args: &Args) -> Result<ScanResult, String> {
@@ -85,13 +168,28 @@ fn run_scan(args: &Args) -> Result<ScanResult, String> {
     let config_path = Path::new(&args.config);
     let openclaw_config = OpenClawConfig::from_file(config_path)?;
 
+    let telemetry = Telemetry::new(TelemetryConfig {
+        enabled: args.otel_endpoint.is_some(),
+        otlp_endpoint: args.otel_endpoint.clone(),
+    });
+
+    let kb = crate::knowledge::KnowledgeBase::load(
+        args.kb_system.as_deref().map(Path::new),
+        args.kb_user.as_deref().map(Path::new),
+    )?;
+
     // Run scanners
     let scanners = get_all_scanners();
     let mut result = ScanResult::new();
+    let scan_start = Instant::now();
 
     for scanner in scanners {
-        let findings = scanner.scan(&openclaw_config);
+        let scanner_start = Instant::now();
+        let findings = scanner.scan(&openclaw_config, &kb);
+        telemetry.record_scan_span(scanner.name(), scanner_start.elapsed(), findings.len());
+
         for finding in findings {
+            telemetry.record_finding(finding.severity);
             match args.severity {
                 SeverityFilter::CriticalOnly => {
                     if finding.severity == Severity::Critical {
@@ -110,6 +208,9 @@ fn run_scan(args: &Args) -> Result<ScanResult, String> {
         }
     }
 
+    result.scan_time_seconds = scan_start.elapsed().as_secs_f64();
+    telemetry.record_scan_summary(result.health_score, result.scan_time_seconds);
+
     Ok(result)
 }
 
@@ -183,6 +284,14 @@ fn display_console(result: &ScanResult, verbose: bool) {
             println!("   Remediation: {}", finding.remediation);
         }
     }
+
+    if !result.waived.is_empty() {
+        println!("\n[ Waived Findings ]");
+        for finding in &result.waived {
+            let line = format!("{} | {} | {}", finding.severity.as_str(), finding.module, finding.title);
+            println!("{}", line.dimmed());
+        }
+    }
 }
 
 fn display_json(result: &ScanResult, output: &Option<String>) {
@@ -290,6 +399,18 @@ fn display_html(result: &ScanResult, output: &Option<String>) {
         ));
     }
     
+    if !result.waived.is_empty() {
+        html.push_str("\n        <h2>Waived Findings</h2>\n");
+        for finding in &result.waived {
+            html.push_str(&format!(
+                "        <p style='color:#9ca3af'>{} &mdash; {} ({})</p>\n",
+                finding.title,
+                finding.severity.as_str(),
+                finding.module
+            ));
+        }
+    }
+
     html.push_str(&format!(r#"
         <div class="footer">
             <p>Scanned by Dino-AISS v0.1.0 - AI Assistant Security Scanner</p>
@@ -362,6 +483,18 @@ fn display_markdown(result: &ScanResult, output: &Option<String>) {
         ));
     }
     
+    if !result.waived.is_empty() {
+        md.push_str("\n## Waived Findings\n\n");
+        for finding in &result.waived {
+            md.push_str(&format!(
+                "- *{}* — {} ({})\n",
+                finding.title,
+                finding.severity.as_str(),
+                finding.module
+            ));
+        }
+    }
+
     if let Some(path) = output {
         std::fs::write(path, &md).unwrap();
         println!("OK Results written to: {}", path);
@@ -370,6 +503,121 @@ fn display_markdown(result: &ScanResult, output: &Option<String>) {
     }
 }
 
+fn cyclonedx_rating(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::High => "high",
+        Severity::Medium => "medium",
+        Severity::Low => "low",
+        Severity::Info => "none",
+    }
+}
+
+/// Derive a CycloneDX VEX `analysis.state` for `cve` rather than asserting
+/// `exploitable` for every finding: an unparseable installed version means
+/// we genuinely don't know, and a patched version means the CVE doesn't
+/// apply here even though the scanner flagged the pattern it guards against.
+fn vex_state(cve: &str, version: &str, kb: &crate::knowledge::KnowledgeBase) -> &'static str {
+    if Version::parse(version).is_none() {
+        "in_triage"
+    } else if kb.is_affected(cve, version) {
+        "exploitable"
+    } else {
+        "not_affected"
+    }
+}
+
+fn display_cyclonedx(result: &ScanResult, version: &str, output: &Option<String>) {
+    let purl = format!("pkg:npm/openclaw@{}", version);
+    let kb = crate::knowledge::KnowledgeBase::default();
+
+    // Only CVE-backed findings are genuine vulnerability entries; the rest
+    // are config-hardening advice with no CVE to analyze exploitability
+    // against, so they're reported as advisories instead.
+    let (cve_findings, advisory_findings): (Vec<_>, Vec<_>) =
+        result.findings.iter().partition(|f| f.cve.is_some());
+
+    let vulnerabilities: Vec<serde_json::Value> = cve_findings
+        .iter()
+        .map(|finding| {
+            let cve = finding.cve.as_deref().unwrap();
+            serde_json::json!({
+                "id": cve,
+                "source": { "name": "Dino-AISS" },
+                "ratings": [{
+                    "source": { "name": "Dino-AISS" },
+                    "severity": cyclonedx_rating(&finding.severity),
+                }],
+                "affects": [{ "ref": purl }],
+                "analysis": {
+                    "state": vex_state(cve, version, &kb),
+                    "detail": finding.remediation,
+                },
+                "description": finding.description,
+                "properties": [
+                    { "name": "dino-aiss:module", "value": finding.module },
+                    { "name": "dino-aiss:config_path", "value": finding.config_path },
+                ],
+            })
+        })
+        .collect();
+
+    let advisories: Vec<serde_json::Value> = advisory_findings
+        .iter()
+        .map(|finding| {
+            serde_json::json!({
+                "id": finding.id,
+                "title": finding.title,
+                "source": { "name": "Dino-AISS" },
+                "ratings": [{
+                    "source": { "name": "Dino-AISS" },
+                    "severity": cyclonedx_rating(&finding.severity),
+                }],
+                "affects": [{ "ref": purl }],
+                "description": finding.description,
+                "recommendation": finding.remediation,
+                "properties": [
+                    { "name": "dino-aiss:module", "value": finding.module },
+                    { "name": "dino-aiss:config_path", "value": finding.config_path },
+                ],
+            })
+        })
+        .collect();
+
+    let bom = serde_json::json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "component": {
+                "type": "application",
+                "name": "openclaw",
+                "purl": purl,
+            }
+        },
+        "vulnerabilities": vulnerabilities,
+        "advisories": advisories,
+    });
+
+    let json = serde_json::to_string_pretty(&bom).unwrap();
+    if let Some(path) = output {
+        std::fs::write(path, &json).unwrap();
+        println!("OK Results written to: {}", path);
+    } else {
+        println!("{}", json);
+    }
+}
+
+fn display_sarif(result: &ScanResult, output: &Option<String>) {
+    let sarif = report::render(&result.findings, report::ReportFormat::Sarif);
+    if let Some(path) = output {
+        std::fs::write(path, &sarif).unwrap();
+        println!("OK Results written to: {}", path);
+    } else {
+        println!("{}", sarif);
+    }
+}
+
 fn generate_fix_suggestions(result: &ScanResult) -> Vec<String> {
     let mut suggestions = Vec::new();
     
@@ -384,9 +632,10 @@ fn generate_fix_suggestions(result: &ScanResult) -> Vec<String> {
             "tools.fs_workspace_only_disabled" => "Set tools.fs.workspaceOnly to true".to_string(),
             "tools.web_fetch_no_ssrf" => "Set tools.webFetch.ssrfPolicy to 'strict'".to_string(),
             "tools.web_search_no_ssrf" => "Set tools.webSearch.ssrfPolicy to 'strict'".to_string(),
+            "tools.dangerous_tool_no_confirmation" => "Add the tool name to tools.confirm.pattern (e.g. 'execute_.*|fs_write|fs_rm') or deny it in tools.deny".to_string(),
             "gateway.auth_none" => "Set gateway.auth.mode to 'token' or 'password'".to_string(),
             "gateway.bind_public" => "Set gateway.bind to 'loopback'".to_string(),
-            "gateway.weak_token" => "Use a token with at least 32 random characters".to_string(),
+            "gateway.low_entropy_token" => "Use a token with at least 128 bits of true randomness (32+ random bytes, base64/hex encoded)".to_string(),
             "gateway.tailscale_funnel" => "Set gateway.tailscale.funnel to false".to_string(),
             "session.dm_scope_main_multi_channel" => "Set session.dmScope to 'per-channel-peer'".to_string(),
             "channel.telegram.dm_policy_open" => "Set channels.telegram.dmPolicy to 'pairing' or 'allowlist'".to_string(),
@@ -426,17 +675,55 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ("CVE-2026-24763", "2026.2.13"),
         ];
         
+        let mut findings = Vec::new();
+
         for (cve, min_version) in cve_requirements {
             println!("{}: requires >= {}", cve, min_version);
-            // Simple version comparison (would need proper semver in production)
-            let version_str = version.as_str();
-            if version_str < min_version {
-                println!("  [{}] UPGRADE NEEDED!", "FAIL".red());
-            } else {
-                println!("  [{}]", "OK".green());
+
+            let installed = Version::parse(version);
+            let minimum = Version::parse(min_version);
+
+            match (installed, minimum) {
+                (Some(installed), Some(minimum)) if installed < minimum => {
+                    println!("  [{}] UPGRADE NEEDED!", "FAIL".red());
+                    findings.push(
+                        crate::models::Finding::new(
+                            &format!("version.vulnerable_to_{}", cve),
+                            "version",
+                            Severity::High,
+                            &format!("Running Version Vulnerable to {}", cve),
+                            &format!(
+                                "Installed version {} is below the patched version {}",
+                                installed, minimum
+                            ),
+                            "This instance is susceptible to the associated CVE",
+                            &format!("Upgrade OpenClaw to {} or later", minimum),
+                            "version",
+                        )
+                        .with_cve(cve),
+                    );
+                }
+                (Some(_), Some(_)) => println!("  [{}]", "OK".green()),
+                _ => println!("  [{}] UNABLE TO PARSE VERSION", "WARN".yellow()),
             }
         }
-        
+
+        if !findings.is_empty() {
+            println!("\n[ Version Findings ]");
+            for finding in &findings {
+                println!(
+                    "- {} ({}){}",
+                    finding.title,
+                    finding.severity.as_str(),
+                    finding
+                        .cve
+                        .as_ref()
+                        .map(|c| format!(" [{}]", c))
+                        .unwrap_or_default()
+                );
+            }
+        }
+
         return Ok(());
     }
 
@@ -454,6 +741,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     println!("{} ({:.2}s)", "OK".green(), start.elapsed().as_secs_f32());
 
+    let mut result = result;
+    if let Some(baseline_path) = &args.baseline {
+        match Baseline::from_file(Path::new(baseline_path)) {
+            Ok(loaded) => {
+                let today = Local::now().format("%Y-%m-%d").to_string();
+                baseline::apply(&mut result, &loaded, &today);
+            }
+            Err(e) => {
+                eprintln!("Warning: failed to load baseline: {}", e);
+            }
+        }
+    }
+    correlation::apply(&mut result, &correlation::default_rules());
+    let result = result;
+
     // Handle --fix mode
     if args.fix {
         println!("\n[ Auto-Fix Suggestions ]");
@@ -512,10 +814,43 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         OutputFormat::Json => display_json(&result, &args.output),
         OutputFormat::Markdown => display_markdown(&result, &args.output),
         OutputFormat::Html => display_html(&result, &args.output),
+        OutputFormat::Cyclonedx => {
+            let version = openclaw_version_from_config(&args.config);
+            display_cyclonedx(&result, &version, &args.output)
+        }
+        OutputFormat::Sarif => display_sarif(&result, &args.output),
     }
 
-    // Exit code based on severity
-    if result.critical_count() > 0 {
+    // Severity-budget policy gate: an explicit --policy/--fail-on opts out
+    // of the default any-critical-or-high rule below in favor of a
+    // configurable budget with waivable finding ids.
+    if args.policy.is_some() || args.fail_on.is_some() {
+        let loaded_policy = match &args.policy {
+            Some(path) => match policy::Policy::from_file(Path::new(path)) {
+                Ok(p) => p,
+                Err(e) => {
+                    eprintln!("Warning: failed to load policy, falling back to --fail-on: {}", e);
+                    args.fail_on
+                        .clone()
+                        .map(|s| policy::Policy::fail_on(s.to_severity()))
+                        .unwrap_or_default()
+                }
+            },
+            None => policy::Policy::fail_on(args.fail_on.clone().unwrap().to_severity()),
+        };
+
+        let summary = policy::evaluate(&result, &loaded_policy);
+        println!("\n[ Policy Gate ]");
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&summary).unwrap_or_default()
+        );
+
+        if !summary.passed {
+            eprintln!("Policy gate failed: {} violation(s)", summary.violations.len());
+            std::process::exit(3);
+        }
+    } else if result.critical_count() > 0 {
         std::process::exit(2);
     } else if result.high_count() > 0 {
         std::process::exit(1);
@@ -537,11 +872,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ("2026.2.23", "Security hardening batch, safe bins updates"),
         ];
         
+        let current = Version::parse(&version);
+
         for (ver, desc) in upgrades {
-            let status = "[upgrade]".yellow();
-            println!("  {} v{} - {}", status, ver, desc);
+            let needed = match (current, Version::parse(ver)) {
+                (Some(current), Some(target)) => current < target,
+                _ => true,
+            };
+
+            if needed {
+                let status = "[upgrade]".yellow();
+                println!("  {} v{} - {}", status, ver, desc);
+            } else {
+                println!("  {} v{} - {}", "[ok]".green(), ver, desc);
+            }
         }
-        
+
         println!("\nTo upgrade: npm update -g openclaw");
         
         return Ok(());
@@ -550,6 +896,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn openclaw_version_from_config(_config_path: &str) -> String {
-    "unknown".to_string()
+fn openclaw_version_from_config(config_path: &str) -> String {
+    let Ok(content) = std::fs::read_to_string(config_path) else {
+        return "unknown".to_string();
+    };
+
+    let Ok(data) = serde_json::from_str::<serde_json::Value>(&content)
+        .or_else(|_| serde_yaml::from_str::<serde_json::Value>(&content))
+    else {
+        return "unknown".to_string();
+    };
+
+    data.get("version")
+        .or_else(|| data.get("openclaw").and_then(|v| v.get("version")))
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .unwrap_or_else(|| "unknown".to_string())
 }
@@ -88,6 +88,8 @@ impl Finding {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanResult {
     pub findings: Vec<Finding>,
+    #[serde(default)]
+    pub waived: Vec<Finding>,
     pub health_score: i32,
     pub scan_time_seconds: f64,
 }
@@ -96,6 +98,7 @@ impl ScanResult {
     pub fn new() -> Self {
         Self {
             findings: Vec::new(),
+            waived: Vec::new(),
             health_score: 100,
             scan_time_seconds: 0.0,
         }
@@ -0,0 +1,160 @@
+//! Path Containment Resolver
+//!
+//! Shared hardening for any filesystem-bound plugin/skill location (a
+//! ClawHub skill URL, a plugin install path, ...): decodes percent-encoding
+//! iteratively, normalizes path separators, and lexically resolves `.`/`..`
+//! segments against a trusted root so traversal can't hide behind encoding
+//! tricks or backslashes. Substring checks like `contains("..")` both miss
+//! `%2e%2e`/double-encoding/backslash segments and false-positive on a
+//! literal `..` sitting in a query string.
+
+/// Decode a single pass of `%XX` escapes. Returns `None` if nothing changed,
+/// so callers can detect a fixed point. Operates on bytes so a malformed or
+/// mid-sequence `%` never panics on a non-UTF-8 char boundary.
+fn decode_percent_once(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    let mut changed = false;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 3 <= bytes.len() {
+            let hi = (bytes[i + 1] as char).to_digit(16);
+            let lo = (bytes[i + 2] as char).to_digit(16);
+            if let (Some(hi), Some(lo)) = (hi, lo) {
+                out.push((hi * 16 + lo) as u8);
+                i += 3;
+                changed = true;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    changed.then(|| String::from_utf8_lossy(&out).into_owned())
+}
+
+/// Decode `%XX` escapes until the string stops changing (or a small
+/// iteration cap is hit), so double/triple encoding like `%252e%252e`
+/// resolves to `..` instead of slipping past a single-pass decoder.
+fn decode_percent_iteratively(input: &str) -> String {
+    let mut current = input.to_string();
+    for _ in 0..8 {
+        match decode_percent_once(&current) {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+    current
+}
+
+/// Strip a trailing query/fragment, leaving just the path-ish portion that
+/// traversal segments would live in (a literal `..` in `?ref=../x` isn't a
+/// traversal attempt). The scheme/host of a URL `root`/`candidate` pair are
+/// deliberately left in place as ordinary path components, so a skill URL
+/// is checked against a root expressed the same way (e.g.
+/// `https://clawhub.dev/skills`).
+fn path_component(input: &str) -> &str {
+    let end = input.find(['?', '#']).unwrap_or(input.len());
+    &input[..end]
+}
+
+fn normalize_root(root: &str) -> Vec<&str> {
+    root.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+/// Lexically resolve `candidate` against `root`: decode, normalize
+/// separators, then walk components collapsing `.`/`..` (an absolute
+/// candidate discards the root and starts from `/`). Returns the resolved
+/// path; it is the caller's job to check it's still under `root` via
+/// [`is_contained`].
+fn resolve(root: &str, candidate: &str) -> Vec<String> {
+    let stripped = path_component(candidate);
+    let decoded = decode_percent_iteratively(stripped);
+    let normalized = decoded.replace('\\', "/");
+
+    let mut stack: Vec<String> = normalize_root(root).iter().map(|s| s.to_string()).collect();
+    if normalized.starts_with('/') {
+        stack.clear();
+    }
+
+    for segment in normalized.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                stack.pop();
+            }
+            other => stack.push(other.to_string()),
+        }
+    }
+
+    stack
+}
+
+/// Does `candidate` (a skill URL, a plugin install path, ...) stay under
+/// `root` once encoding, separators, and `.`/`..` segments are resolved?
+pub fn is_contained(root: &str, candidate: &str) -> bool {
+    let resolved = resolve(root, candidate);
+    let root_components = normalize_root(root);
+    resolved.len() >= root_components.len()
+        && resolved
+            .iter()
+            .zip(root_components.iter())
+            .all(|(a, b)| a.as_str() == *b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT: &str = "/var/lib/openclaw/skills";
+
+    #[test]
+    fn plain_subpath_is_contained() {
+        assert!(is_contained(ROOT, "my-skill/index.js"));
+    }
+
+    #[test]
+    fn literal_dotdot_is_rejected() {
+        assert!(!is_contained(ROOT, "../../etc/passwd"));
+    }
+
+    #[test]
+    fn percent_encoded_dotdot_is_rejected() {
+        assert!(!is_contained(ROOT, "%2e%2e/%2e%2e/etc/passwd"));
+    }
+
+    #[test]
+    fn double_percent_encoded_dotdot_is_rejected() {
+        assert!(!is_contained(ROOT, "%252e%252e/%252e%252e/etc/passwd"));
+    }
+
+    #[test]
+    fn backslash_segments_are_rejected() {
+        assert!(!is_contained(ROOT, "..\\..\\etc\\passwd"));
+    }
+
+    #[test]
+    fn absolute_path_outside_root_is_rejected() {
+        assert!(!is_contained(ROOT, "/etc/passwd"));
+    }
+
+    #[test]
+    fn absolute_path_under_root_is_contained() {
+        assert!(is_contained(ROOT, "/var/lib/openclaw/skills/my-skill"));
+    }
+
+    #[test]
+    fn query_string_dotdot_is_not_a_false_positive() {
+        assert!(is_contained(
+            ROOT,
+            "https://clawhub.dev/skills/my-skill?ref=../changelog"
+        ));
+    }
+
+    #[test]
+    fn traversal_that_returns_under_root_is_contained() {
+        assert!(is_contained(ROOT, "sub/../my-skill"));
+    }
+}
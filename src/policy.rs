@@ -0,0 +1,118 @@
+//! Severity-Budget Policy Gate
+//!
+//! Lets CI fail a build against an aggregate budget across every scanner
+//! (e.g. "max 0 critical, max 2 high") instead of an unconditional
+//! any-critical-or-high rule. A policy can also waive specific finding ids
+//! so an accepted risk doesn't break the gate.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ScanResult, Severity};
+
+/// Maximum allowed count per severity. `None` means "no budget" (unlimited).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Policy {
+    #[serde(default)]
+    pub max_critical: Option<usize>,
+    #[serde(default)]
+    pub max_high: Option<usize>,
+    #[serde(default)]
+    pub max_medium: Option<usize>,
+    #[serde(default)]
+    pub max_low: Option<usize>,
+    /// Finding ids excluded from every count below, e.g. an accepted risk
+    /// that's been through its own review outside the baseline waiver file.
+    #[serde(default)]
+    pub waived_ids: Vec<String>,
+}
+
+impl Policy {
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read policy file: {}", e))?;
+        toml::from_str(&content).map_err(|e| format!("Failed to parse policy file: {}", e))
+    }
+
+    /// Equivalent to `--fail-on <severity>`: zero tolerance for that
+    /// severity and everything above it.
+    pub fn fail_on(severity: Severity) -> Self {
+        let mut policy = Policy::default();
+        if matches!(
+            severity,
+            Severity::Critical | Severity::High | Severity::Medium | Severity::Low
+        ) {
+            policy.max_critical = Some(0);
+        }
+        if matches!(severity, Severity::High | Severity::Medium | Severity::Low) {
+            policy.max_high = Some(0);
+        }
+        if matches!(severity, Severity::Medium | Severity::Low) {
+            policy.max_medium = Some(0);
+        }
+        if severity == Severity::Low {
+            policy.max_low = Some(0);
+        }
+        policy
+    }
+}
+
+/// Structured counts/violations from evaluating a `ScanResult` against a
+/// `Policy`, modeled on the same audit-summary shape as other structured
+/// output so CI can assert on specific thresholds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicySummary {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+    pub info: usize,
+    pub cve_tagged: usize,
+    pub violations: Vec<String>,
+    pub passed: bool,
+}
+
+pub fn evaluate(result: &ScanResult, policy: &Policy) -> PolicySummary {
+    let counted: Vec<_> = result
+        .findings
+        .iter()
+        .filter(|f| !policy.waived_ids.contains(&f.id))
+        .collect();
+
+    let count_of = |severity: Severity| counted.iter().filter(|f| f.severity == severity).count();
+    let critical = count_of(Severity::Critical);
+    let high = count_of(Severity::High);
+    let medium = count_of(Severity::Medium);
+    let low = count_of(Severity::Low);
+    let info = count_of(Severity::Info);
+    let cve_tagged = counted.iter().filter(|f| f.cve.is_some()).count();
+
+    let mut violations = Vec::new();
+    let mut check_budget = |label: &str, actual: usize, max: Option<usize>| {
+        if let Some(max) = max {
+            if actual > max {
+                violations.push(format!(
+                    "{} findings ({}) exceed budget of {}",
+                    label, actual, max
+                ));
+            }
+        }
+    };
+    check_budget("critical", critical, policy.max_critical);
+    check_budget("high", high, policy.max_high);
+    check_budget("medium", medium, policy.max_medium);
+    check_budget("low", low, policy.max_low);
+
+    PolicySummary {
+        critical,
+        high,
+        medium,
+        low,
+        info,
+        cve_tagged,
+        passed: violations.is_empty(),
+        violations,
+    }
+}
@@ -0,0 +1,127 @@
+//! Standardized Finding Report Emitters
+//!
+//! Every scanner returns plain `Vec<Finding>` with no serialization layer of
+//! its own. This module adds a `Reporter` trait plus concrete emitters
+//! (SARIF 2.1.0, plain JSON, and a compact "simple JSON" summary) so scan
+//! results can be wired into CI dashboards and code-scanning UIs that expect
+//! `--format json|simple-json|sarif`.
+
+use crate::models::{Finding, Severity};
+
+/// Renders a set of findings into some serialized report format.
+pub trait Reporter {
+    fn render(&self, findings: &[Finding]) -> String;
+}
+
+fn sarif_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info => "note",
+    }
+}
+
+/// SARIF 2.1.0 output, suitable for GitHub code-scanning / CI dashboards.
+pub struct SarifReporter;
+
+impl Reporter for SarifReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        let results: Vec<serde_json::Value> = findings
+            .iter()
+            .map(|finding| {
+                let mut properties = serde_json::Map::new();
+                if let Some(cve) = &finding.cve {
+                    properties.insert("relatedCve".to_string(), serde_json::Value::String(cve.clone()));
+                    properties.insert(
+                        "taxa".to_string(),
+                        serde_json::json!([{ "id": cve, "toolComponent": { "name": "CVE" } }]),
+                    );
+                }
+
+                serde_json::json!({
+                    "ruleId": finding.id,
+                    "level": sarif_level(&finding.severity),
+                    "message": { "text": finding.description },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": finding.config_path }
+                        }
+                    }],
+                    "fixes": [{
+                        "description": { "text": finding.remediation }
+                    }],
+                    "properties": properties,
+                })
+            })
+            .collect();
+
+        let sarif = serde_json::json!({
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "Dino-AISS",
+                        "informationUri": "https://github.com/RekitRex21/Dino-AISS",
+                        "version": "0.1.0",
+                    }
+                },
+                "results": results,
+            }]
+        });
+
+        serde_json::to_string_pretty(&sarif).unwrap_or_default()
+    }
+}
+
+/// Plain JSON array of full `Finding` structs.
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        serde_json::to_string_pretty(findings).unwrap_or_default()
+    }
+}
+
+/// Compact summary JSON: just the fields needed to triage at a glance.
+pub struct SimpleJsonReporter;
+
+impl Reporter for SimpleJsonReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        let summary: Vec<serde_json::Value> = findings
+            .iter()
+            .map(|finding| {
+                serde_json::json!({
+                    "id": finding.id,
+                    "severity": finding.severity.as_str(),
+                    "title": finding.title,
+                    "cve": finding.cve,
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&summary).unwrap_or_default()
+    }
+}
+
+/// Output formats this module knows how to render findings into. CycloneDX
+/// VEX is deliberately not one of them: it needs the OpenClaw version and
+/// knowledge base to derive a real `analysis.state` per finding, which this
+/// module's plain `Reporter` trait has no way to thread through - see
+/// `main.rs`'s `display_cyclonedx` for that format's actual entry point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Sarif,
+    Json,
+    SimpleJson,
+}
+
+/// Single entry point: render `findings` into `format`'s serialized form.
+pub fn render(findings: &[Finding], format: ReportFormat) -> String {
+    let reporter: Box<dyn Reporter> = match format {
+        ReportFormat::Sarif => Box::new(SarifReporter),
+        ReportFormat::Json => Box::new(JsonReporter),
+        ReportFormat::SimpleJson => Box::new(SimpleJsonReporter),
+    };
+    reporter.render(findings)
+}
@@ -24,7 +24,7 @@ impl Scanner for BrowserScanner {
         "Browser automation security"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         // Check for browser configuration in raw config
@@ -0,0 +1,136 @@
+//! Capability Permission Scanner
+//!
+//! Priority: HIGH
+//!
+//! Checks `tools.capabilities` - a Tauri-style fine-grained permission list
+//! that sits alongside (and can override) the coarse `tools.profile`/
+//! `tools.deny` model:
+//! - a capability grants a normally-restricted tool globally with no scope
+//! - a capability re-allows a tool also present in `tools.deny`
+//! - `exec` is granted with an empty or `*` scope (no binary restriction)
+
+use crate::config::{Capability, OpenClawConfig};
+use crate::models::{Finding, Severity};
+use crate::scanner::Scanner;
+
+/// Tools the default profile restricts unless explicitly opened up; granting
+/// one of these globally via a capability defeats that default posture.
+const RESTRICTED_BY_DEFAULT: &[&str] = &["exec", "elevated", "fs_write", "fs_rm", "browser_control"];
+
+/// Profile values that already grant everything, so a capability re-granting
+/// a restricted tool on top of them isn't adding anything new.
+const UNRESTRICTED_PROFILES: &[&str] = &["admin", "full", "*"];
+
+fn is_wildcard(pattern: &str) -> bool {
+    pattern == "*" || pattern == "all"
+}
+
+/// Matches a capability's `tool` glob (e.g. `fs_*`) against a plain tool
+/// name, or a literal tool name against another literal/glob. Only a single
+/// trailing `*` is supported - that's the only form the schema documents.
+fn glob_match(pattern: &str, value: &str) -> bool {
+    if pattern == value || is_wildcard(pattern) {
+        return true;
+    }
+    match pattern.strip_suffix('*') {
+        Some(prefix) => value.starts_with(prefix),
+        None => false,
+    }
+}
+
+fn has_scope(capability: &Capability) -> bool {
+    capability.scope.as_ref().is_some_and(|scope| {
+        !scope.is_empty() && !scope.iter().any(|s| is_wildcard(s))
+    })
+}
+
+pub struct CapabilityScanner;
+
+impl Scanner for CapabilityScanner {
+    fn name(&self) -> &str {
+        "capabilities"
+    }
+
+    fn description(&self) -> &str {
+        "Fine-grained tool capability and scope permissions"
+    }
+
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let Some(capabilities) = &config.tools.capabilities else {
+            return findings;
+        };
+
+        let profile_unrestricted = config
+            .tools
+            .profile
+            .as_deref()
+            .is_some_and(|p| UNRESTRICTED_PROFILES.contains(&p));
+
+        let empty_deny: Vec<String> = Vec::new();
+        let deny_list = config.tools.deny.as_deref().unwrap_or(&empty_deny);
+
+        for capability in capabilities {
+            if capability.permission != "allow" {
+                continue;
+            }
+
+            let restricted_by_default = RESTRICTED_BY_DEFAULT
+                .iter()
+                .any(|tool| glob_match(&capability.tool, tool) || glob_match(tool, &capability.tool));
+
+            if restricted_by_default && !has_scope(capability) && !profile_unrestricted {
+                findings.push(Finding::new(
+                    "tools.capability_broad_grant",
+                    self.name(),
+                    Severity::High,
+                    &format!("Capability Grants '{}' Globally With No Scope", capability.tool),
+                    &format!(
+                        "tools.capabilities grants '{}' with no scope, bypassing the restricted default profile",
+                        capability.tool
+                    ),
+                    "The tool is usable against any path, binary, or domain rather than the narrow default",
+                    &format!("Add a scope to the '{}' capability limiting what it can act on", capability.tool),
+                    "tools.capabilities",
+                ));
+            }
+
+            if glob_match(&capability.tool, "exec") && !has_scope(capability) {
+                findings.push(Finding::new(
+                    "tools.capability_exec_unscoped",
+                    self.name(),
+                    Severity::Critical,
+                    "Exec Capability Has No Binary Scope",
+                    &format!(
+                        "tools.capabilities grants '{}' with no (or wildcard) binary scope",
+                        capability.tool
+                    ),
+                    "Any binary can be executed instead of a vetted allowlist",
+                    "Scope the exec capability to specific binaries",
+                    "tools.capabilities",
+                ));
+            }
+
+            let conflicts_with_deny = deny_list
+                .iter()
+                .any(|denied| glob_match(&capability.tool, denied) || glob_match(denied, &capability.tool));
+            if conflicts_with_deny {
+                findings.push(Finding::new(
+                    "tools.capability_deny_conflict",
+                    self.name(),
+                    Severity::Medium,
+                    &format!("Capability Re-Allows Denied Tool '{}'", capability.tool),
+                    &format!(
+                        "tools.capabilities allows '{}', but tools.deny also lists an entry covering it",
+                        capability.tool
+                    ),
+                    "Which rule wins is ambiguous and depends on evaluation order, risking an unintended grant",
+                    &format!("Remove the conflicting tools.deny entry or the '{}' capability", capability.tool),
+                    "tools.capabilities",
+                ));
+            }
+        }
+
+        findings
+    }
+}
@@ -1,17 +1,26 @@
 //! Channel Security Scanner
-//! 
+//!
 //! Priority: HIGH
-//! 
+//!
 //! Checks per channel (Telegram, Discord, WhatsApp, Slack, iMessage, Signal):
 //! - DM policy (pairing/allowlist/open/disabled)
 //! - Group policy (mention gating)
 //! - Command authorization
 //! - allowFrom ID-only enforcement
+//! - Per-group overrides of the channel's groupPolicy/allowFrom/command auth
 
 use crate::config::OpenClawConfig;
 use crate::models::{Finding, Severity};
 use crate::scanner::Scanner;
 
+/// Heuristic: a stable platform ID (Slack `U0123ABC`, Discord snowflake,
+/// numeric Telegram chat id, ...) contains a digit and no spaces; a
+/// username/display name typically has neither constraint and can be
+/// reassigned out from under an allowlist.
+fn looks_like_id(value: &str) -> bool {
+    !value.is_empty() && !value.contains(' ') && value.chars().any(|c| c.is_ascii_digit())
+}
+
 pub struct ChannelScanner;
 
 impl Scanner for ChannelScanner {
@@ -23,7 +32,7 @@ impl Scanner for ChannelScanner {
         "Per-channel security configuration"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         for (channel_name, channel) in &config.channels {
@@ -88,6 +97,93 @@ impl Scanner for ChannelScanner {
                     ));
                 }
             }
+
+            // Per-group analysis: a channel-level groupPolicy/allowFrom is
+            // only as restrictive as the least restrictive group override.
+            if let Some(groups) = &channel.groups {
+                for (group_id, group_val) in groups {
+                    let Some(group_obj) = group_val.as_object() else {
+                        continue;
+                    };
+
+                    let group_policy = group_obj.get("policy").and_then(|v| v.as_str());
+                    let group_allow_from: Vec<String> = group_obj
+                        .get("allowFrom")
+                        .and_then(|v| v.as_array())
+                        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                        .unwrap_or_default();
+                    let command_auth_enabled =
+                        group_obj.get("commandAuth").and_then(|v| v.as_bool()) == Some(true);
+
+                    // Check: group re-opens a restrictive channel groupPolicy - critical
+                    if group_policy == Some("open") && channel.group_policy.as_deref() != Some("open") {
+                        findings.push(Finding::new(
+                            &format!("channel.{}.group.{}.policy_reopened", channel_name, group_id),
+                            self.name(),
+                            Severity::Critical,
+                            &format!("{} Group '{}' Reopens groupPolicy", channel_name, group_id),
+                            &format!(
+                                "Group '{}' sets policy 'open', overriding the channel's restrictive groupPolicy",
+                                group_id
+                            ),
+                            "Any member of this specific group can trigger the agent despite the channel default",
+                            &format!("Set channels.{}.groups.{}.policy to match the channel default", channel_name, group_id),
+                            &format!("channels.{}.groups.{}.policy", channel_name, group_id),
+                        ));
+                    }
+
+                    // Check: group allowFrom wildcard - medium
+                    if group_allow_from.iter().any(|v| v == "*") {
+                        findings.push(Finding::new(
+                            &format!("channel.{}.group.{}.allow_from_wildcard", channel_name, group_id),
+                            self.name(),
+                            Severity::Medium,
+                            &format!("{} Group '{}' allowFrom Uses Wildcard", channel_name, group_id),
+                            &format!("Group '{}' allowFrom includes '*' - allows everyone in the group", group_id),
+                            "Any member of this group can interact with the agent",
+                            "Use specific user IDs instead of '*'",
+                            &format!("channels.{}.groups.{}.allowFrom", channel_name, group_id),
+                        ));
+                    }
+
+                    // Check: group allowFrom has spoofable usernames instead of IDs - medium
+                    let non_id_entries: Vec<&str> = group_allow_from
+                        .iter()
+                        .map(String::as_str)
+                        .filter(|v| *v != "*" && !looks_like_id(v))
+                        .collect();
+                    if !non_id_entries.is_empty() {
+                        findings.push(Finding::new(
+                            &format!("channel.{}.group.{}.allow_from_not_id", channel_name, group_id),
+                            self.name(),
+                            Severity::Medium,
+                            &format!("{} Group '{}' allowFrom Uses Usernames Instead of IDs", channel_name, group_id),
+                            &format!(
+                                "Group '{}' allowFrom contains non-ID entries: {}",
+                                group_id,
+                                non_id_entries.join(", ")
+                            ),
+                            "Usernames/display names can be changed or reassigned, unlike stable platform IDs",
+                            "Replace allowFrom entries with stable platform user IDs",
+                            &format!("channels.{}.groups.{}.allowFrom", channel_name, group_id),
+                        ));
+                    }
+
+                    // Check: command authorization enabled without any member allowlist - high
+                    if command_auth_enabled && group_allow_from.is_empty() {
+                        findings.push(Finding::new(
+                            &format!("channel.{}.group.{}.command_auth_no_allowlist", channel_name, group_id),
+                            self.name(),
+                            Severity::High,
+                            &format!("{} Group '{}' Command Auth Without Allowlist", channel_name, group_id),
+                            &format!("Group '{}' enables commandAuth but has no allowFrom entries", group_id),
+                            "Any group member can authorize privileged commands",
+                            &format!("Add channels.{}.groups.{}.allowFrom entries or disable commandAuth", channel_name, group_id),
+                            &format!("channels.{}.groups.{}.commandAuth", channel_name, group_id),
+                        ));
+                    }
+                }
+            }
         }
 
         findings
@@ -25,7 +25,7 @@ impl Scanner for ControlPlaneScanner {
         "Control plane tools access control"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
         
         // Check tools.deny for control plane tools
@@ -1,18 +1,88 @@
 //! Credentials & Secret Detector Scanner
-//! 
+//!
 //! Priority: CRITICAL
-//! 
+//!
 //! Checks:
 //! - Token exposure in config (redaction detection)
 //! - File permissions (600 on files, 700 on dirs)
 //! - Environment variable secrets
 //! - Legacy auth vulnerabilities
 //! - OAuth token detection
+//! - Shannon-entropy secret detection over every string value in the config
+//! - Embedded PEM/SSH private key material
 
 use crate::config::OpenClawConfig;
 use crate::models::{Finding, Severity};
 use crate::scanner::Scanner;
 
+/// Minimum length a string value must reach before entropy analysis is
+/// worth running on it - shorter strings (flags, short ids) produce noisy
+/// entropy readings either way.
+const MIN_SECRET_LEN: usize = 20;
+
+/// Shannon entropy `H = -Sum p_i * log2(p_i)` over `s`'s character
+/// frequency distribution, in bits per character.
+fn shannon_entropy(s: &str) -> f64 {
+    let mut counts: std::collections::HashMap<char, usize> = std::collections::HashMap::new();
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    let len = s.chars().count() as f64;
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn is_hex(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_base64_like(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=' | '-' | '_'))
+}
+
+/// Charset-aware entropy threshold (bits/char) a value must clear to be
+/// flagged: hex has a smaller alphabet so its ceiling entropy is lower.
+fn entropy_threshold(s: &str) -> Option<f64> {
+    if is_hex(s) {
+        Some(3.0)
+    } else if is_base64_like(s) {
+        Some(4.0)
+    } else {
+        None
+    }
+}
+
+fn looks_like_private_key(s: &str) -> bool {
+    s.contains("-----BEGIN") && (s.contains("PRIVATE KEY") || s.contains("OPENSSH PRIVATE KEY"))
+}
+
+/// Recursively collect every string value in a JSON document along with its
+/// JSON Pointer (RFC 6901) path, e.g. `/gateway/auth/token`.
+fn collect_strings<'a>(value: &'a serde_json::Value, pointer: &str, out: &mut Vec<(String, &'a str)>) {
+    match value {
+        serde_json::Value::String(s) => out.push((pointer.to_string(), s.as_str())),
+        serde_json::Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_strings(item, &format!("{}/{}", pointer, i), out);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (key, item) in map {
+                let escaped = key.replace('~', "~0").replace('/', "~1");
+                collect_strings(item, &format!("{}/{}", pointer, escaped), out);
+            }
+        }
+        _ => {}
+    }
+}
+
 pub struct CredentialsScanner;
 
 impl Scanner for CredentialsScanner {
@@ -24,7 +94,7 @@ impl Scanner for CredentialsScanner {
         "Credential and secret detection"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
         
         // Check for token exposure in config
@@ -61,23 +131,49 @@ impl Scanner for CredentialsScanner {
             }
         }
 
-        // Check for API keys in config (heuristic: long strings that look like keys)
-        let config_str = serde_json::to_string(&config.raw).unwrap_or_default();
-        let api_key_patterns = ["sk-", "api_", "apikey", "secret", "token"];
-        
-        for pattern in api_key_patterns {
-            if config_str.to_lowercase().contains(pattern) {
+        // Entropy-based secret scan: walk every string value in the raw
+        // config and flag ones whose character distribution is too random
+        // to be ordinary config text.
+        let mut string_values = Vec::new();
+        collect_strings(&config.raw, "", &mut string_values);
+
+        for (pointer, value) in string_values {
+            if looks_like_private_key(value) {
+                findings.push(Finding::new(
+                    "credentials.embedded_private_key",
+                    self.name(),
+                    Severity::Critical,
+                    "Embedded Private Key Material",
+                    &format!("A PEM/SSH private key is embedded directly in the config at {}", pointer),
+                    "Anyone with read access to the config can extract the private key",
+                    "Remove the key from config; load it from a secrets manager or restricted file instead",
+                    &pointer,
+                ));
+                continue;
+            }
+
+            if value.len() < MIN_SECRET_LEN {
+                continue;
+            }
+            let Some(threshold) = entropy_threshold(value) else {
+                continue;
+            };
+
+            let entropy = shannon_entropy(value);
+            if entropy >= threshold {
                 findings.push(Finding::new(
-                    "credentials.potential_secret_found",
+                    "credentials.high_entropy_value",
                     self.name(),
                     Severity::High,
-                    "Potential Secret Detected in Config",
-                    &format!("Found potential secret pattern '{}' in configuration", pattern),
-                    "Sensitive credentials may be exposed",
-                    "Review and ensure secrets are properly secured or redacted",
-                    "config",
+                    "High-Entropy Value Found in Config",
+                    &format!(
+                        "Value at {} has {:.2} bits/char of entropy over {} characters, consistent with an embedded secret",
+                        pointer, entropy, value.len()
+                    ),
+                    "Likely an exposed API key, token, or credential",
+                    "Move this value out of config into a secrets manager or environment variable",
+                    &pointer,
                 ));
-                break; // Only report once
             }
         }
 
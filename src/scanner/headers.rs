@@ -0,0 +1,151 @@
+//! HTTP Security Headers Scanner
+//!
+//! Priority: HIGH
+//!
+//! Checks the gateway/Control UI hardening header bundle (`gateway.headers`):
+//! - Content-Security-Policy presence
+//! - X-Content-Type-Options: nosniff
+//! - Referrer-Policy presence
+//! - Clickjacking protection (X-Frame-Options or CSP frame-ancestors)
+//! - A restrictive Permissions-Policy that disables unused device sensors
+//!
+//! Only applies when the config actually configures a `gateway` (the same
+//! presence check the other config-driven scanners use, e.g. `memory.rs`'s
+//! `raw.get("memory")`) - with no gateway section there's no HTTP surface
+//! serving these headers in the first place, so there's nothing to harden.
+
+use crate::config::OpenClawConfig;
+use crate::models::{Finding, Severity};
+use crate::scanner::Scanner;
+
+/// Device-sensor directives a restrictive Permissions-Policy should disable.
+const SENSITIVE_DIRECTIVES: &[&str] = &["camera", "microphone", "geolocation", "usb"];
+
+/// Does `policy` explicitly disable `directive` (e.g. `camera=()`)?
+fn restricts(directive: &str, policy: &str) -> bool {
+    policy.contains(&format!("{}=()", directive))
+}
+
+pub struct HeadersScanner;
+
+impl Scanner for HeadersScanner {
+    fn name(&self) -> &str {
+        "headers"
+    }
+
+    fn description(&self) -> &str {
+        "HTTP security header hardening for the gateway/Control UI"
+    }
+
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        // No gateway configured at all -> no Control UI/HTTP surface serving
+        // these headers, so skip rather than flag a gateway that doesn't exist.
+        if config.raw.get("gateway").and_then(|v| v.as_object()).is_none() {
+            return findings;
+        }
+
+        let headers = &config.gateway.headers;
+
+        let has_csp = headers
+            .content_security_policy
+            .as_deref()
+            .is_some_and(|v| !v.trim().is_empty());
+        if !has_csp {
+            findings.push(Finding::new(
+                "headers.missing_csp",
+                self.name(),
+                Severity::High,
+                "Missing Content-Security-Policy Header",
+                "The gateway/Control UI does not set a Content-Security-Policy header",
+                "Without a CSP, an injected script in the Control UI can run with full page privileges (XSS)",
+                "Set gateway.headers.contentSecurityPolicy to a restrictive policy, e.g. default-src 'self'",
+                "gateway.headers.contentSecurityPolicy",
+            ));
+        }
+
+        let nosniff_ok = headers
+            .x_content_type_options
+            .as_deref()
+            .is_some_and(|v| v.eq_ignore_ascii_case("nosniff"));
+        if !nosniff_ok {
+            findings.push(Finding::new(
+                "headers.missing_nosniff",
+                self.name(),
+                Severity::Medium,
+                "Missing X-Content-Type-Options: nosniff",
+                "The gateway/Control UI does not set X-Content-Type-Options: nosniff",
+                "Browsers may MIME-sniff a response and execute it as a different content type than intended",
+                "Set gateway.headers.xContentTypeOptions to \"nosniff\"",
+                "gateway.headers.xContentTypeOptions",
+            ));
+        }
+
+        let has_referrer_policy = headers
+            .referrer_policy
+            .as_deref()
+            .is_some_and(|v| !v.trim().is_empty());
+        if !has_referrer_policy {
+            findings.push(Finding::new(
+                "headers.missing_referrer_policy",
+                self.name(),
+                Severity::Medium,
+                "Missing Referrer-Policy Header",
+                "The gateway/Control UI does not set a Referrer-Policy header",
+                "Full request URLs, which may carry tokens or session identifiers, can leak to third-party Referer headers",
+                "Set gateway.headers.referrerPolicy to \"same-origin\" or \"no-referrer\"",
+                "gateway.headers.referrerPolicy",
+            ));
+        }
+
+        let has_frame_protection = headers
+            .x_frame_options
+            .as_deref()
+            .is_some_and(|v| !v.trim().is_empty())
+            || headers
+                .content_security_policy
+                .as_deref()
+                .is_some_and(|v| v.contains("frame-ancestors"));
+        if !has_frame_protection {
+            findings.push(Finding::new(
+                "headers.missing_frame_protection",
+                self.name(),
+                Severity::Medium,
+                "Missing Clickjacking Protection",
+                "Neither X-Frame-Options nor a CSP frame-ancestors directive is set for the gateway/Control UI",
+                "The Control UI can be framed by an attacker-controlled page and used for a clickjacking attack",
+                "Set gateway.headers.xFrameOptions to \"DENY\" or add frame-ancestors 'none' to the CSP",
+                "gateway.headers.xFrameOptions",
+            ));
+        }
+
+        let missing_directives: Vec<&str> = SENSITIVE_DIRECTIVES
+            .iter()
+            .copied()
+            .filter(|d| {
+                !headers
+                    .permissions_policy
+                    .as_deref()
+                    .is_some_and(|p| restricts(d, p))
+            })
+            .collect();
+        if !missing_directives.is_empty() {
+            findings.push(Finding::new(
+                "headers.permissive_permissions_policy",
+                self.name(),
+                Severity::High,
+                "Permissive Permissions-Policy for Device Sensors",
+                &format!(
+                    "Permissions-Policy does not disable: {}",
+                    missing_directives.join(", ")
+                ),
+                "A compromised or malicious script running in the Control UI could access device sensors such as the camera or microphone",
+                "Set gateway.headers.permissionsPolicy to disable unused sensors, e.g. camera=(), microphone=(), geolocation=(), usb=()",
+                "gateway.headers.permissionsPolicy",
+            ));
+        }
+
+        findings
+    }
+}
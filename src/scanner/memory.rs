@@ -23,7 +23,7 @@ impl Scanner for MemoryScanner {
         "Memory and context handling security"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         // Check for memory configuration in raw config
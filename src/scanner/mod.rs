@@ -3,40 +3,56 @@
 //! Scanner Base Trait
 
 use crate::config::OpenClawConfig;
+use crate::knowledge::KnowledgeBase;
 use crate::models::Finding;
 
 pub mod browser;
+pub mod capabilities;
 pub mod channels;
 pub mod control_plane;
 pub mod credentials;
 pub mod gateway;
+pub mod headers;
 pub mod memory;
 pub mod nodes;
 pub mod plugins;
 pub mod prompt_injection;
+pub mod redos;
 pub mod sandbox;
+pub mod schema;
 pub mod session;
+pub mod ssrf;
+pub mod tls;
 pub mod tools;
+pub mod watch;
 
 pub use browser::BrowserScanner;
+pub use capabilities::CapabilityScanner;
 pub use channels::ChannelScanner;
 pub use control_plane::ControlPlaneScanner;
 pub use credentials::CredentialsScanner;
 pub use gateway::GatewayScanner;
+pub use headers::HeadersScanner;
 pub use memory::MemoryScanner;
 pub use nodes::NodeScanner;
 pub use plugins::PluginScanner;
 pub use prompt_injection::PromptInjectionScanner;
+pub use redos::RedosScanner;
 pub use sandbox::SandboxScanner;
+pub use schema::SchemaScanner;
 pub use session::SessionScanner;
+pub use ssrf::SsrfScanner;
+pub use tls::TlsScanner;
 pub use tools::ToolsScanner;
 
 /// Get all available scanners
 pub fn get_all_scanners() -> Vec<Box<dyn Scanner>> {
     vec![
+        Box::new(SchemaScanner),
         Box::new(GatewayScanner),
         Box::new(SandboxScanner),
         Box::new(ToolsScanner),
+        Box::new(SsrfScanner),
         Box::new(SessionScanner),
         Box::new(ChannelScanner),
         Box::new(CredentialsScanner),
@@ -46,6 +62,10 @@ pub fn get_all_scanners() -> Vec<Box<dyn Scanner>> {
         Box::new(MemoryScanner),
         Box::new(PromptInjectionScanner),
         Box::new(PluginScanner),
+        Box::new(RedosScanner),
+        Box::new(CapabilityScanner),
+        Box::new(TlsScanner),
+        Box::new(HeadersScanner),
     ]
 }
 
@@ -57,6 +77,9 @@ pub trait Scanner {
     /// Scanner description
     fn description(&self) -> &str;
 
-    /// Scan the configuration and return findings
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding>;
+    /// Scan the configuration and return findings. `kb` is the resolved
+    /// knowledge base (built-in defaults layered with any operator-supplied
+    /// packs) a scanner should consult for CVE severities/remediations
+    /// instead of embedding its own constant strings.
+    fn scan(&self, config: &OpenClawConfig, kb: &KnowledgeBase) -> Vec<Finding>;
 }
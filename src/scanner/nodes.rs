@@ -1,12 +1,13 @@
 //! Node Security Scanner
-//! 
+//!
 //! Priority: HIGH
-//! 
+//!
 //! Checks:
 //! - Node pairing security
 //! - Command allowlist exposure
 //! - Sensitive command access (camera/screen/SMS)
 //! - System run permissions
+//! - Effective permissions after global/node ACL inheritance
 //!
 //! Note: This scanner checks for node-related configurations in the raw config
 
@@ -14,6 +15,146 @@ use crate::config::OpenClawConfig;
 use crate::models::{Finding, Severity};
 use crate::scanner::Scanner;
 
+const SENSITIVE_CAPABILITIES: &[&str] = &["camera", "screen", "contacts", "sms", "location"];
+
+/// A node's effective permission set once a top-level/global scope
+/// (`nodes.global`) has been merged with the node's own allow/deny entries.
+///
+/// Merge rules:
+/// - `allowed_commands`/`allowed_capabilities` start as the union of the
+///   global and node-scoped allow lists.
+/// - Any entry matching a deny glob (global or node-scoped) is removed,
+///   *except* a literal `*`/`all` wildcard: a narrow deny can't meaningfully
+///   revoke a blanket wildcard grant, so the wildcard survives and the deny
+///   is reported as shadowed instead.
+#[derive(Debug, Clone)]
+pub struct ResolvedNodeAcl {
+    pub node: String,
+    pub allowed_commands: Vec<String>,
+    pub allowed_capabilities: Vec<String>,
+    pub denied: Vec<String>,
+}
+
+impl ResolvedNodeAcl {
+    pub fn has_wildcard_commands(&self) -> bool {
+        self.allowed_commands.iter().any(|c| is_wildcard(c))
+    }
+
+    pub fn sensitive_capabilities(&self) -> Vec<&str> {
+        self.allowed_capabilities
+            .iter()
+            .map(String::as_str)
+            .filter(|c| SENSITIVE_CAPABILITIES.contains(c))
+            .collect()
+    }
+
+    /// Deny entries (global or node-scoped) that are present but have no
+    /// effect because a surviving wildcard grant still covers everything they
+    /// tried to block.
+    pub fn shadowed_denies(&self) -> Vec<&str> {
+        let wildcard_survives = self.has_wildcard_commands()
+            || self.allowed_capabilities.iter().any(|c| is_wildcard(c));
+        if !wildcard_survives {
+            return Vec::new();
+        }
+        self.denied
+            .iter()
+            .map(String::as_str)
+            .filter(|d| !is_wildcard(d))
+            .collect()
+    }
+}
+
+fn is_wildcard(pattern: &str) -> bool {
+    pattern == "*" || pattern == "all"
+}
+
+/// Minimal glob matcher supporting a single or multiple `*` wildcards
+/// (e.g. `fs_*`, `*_admin`, `a*b`). A pattern with no `*` must match exactly.
+fn glob_match(pattern: &str, value: &str) -> bool {
+    if pattern == value || is_wildcard(pattern) {
+        return true;
+    }
+    if !pattern.contains('*') {
+        return false;
+    }
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let mut rest = value;
+
+    if let Some(first) = parts.first() {
+        if !first.is_empty() {
+            if !rest.starts_with(first) {
+                return false;
+            }
+            rest = &rest[first.len()..];
+        }
+    }
+
+    for part in &parts[1..parts.len().saturating_sub(1)] {
+        if part.is_empty() {
+            continue;
+        }
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+
+    if let Some(last) = parts.last() {
+        if !last.is_empty() {
+            return rest.ends_with(last);
+        }
+    }
+
+    true
+}
+
+fn string_list(obj: &serde_json::Map<String, serde_json::Value>, key: &str) -> Vec<String> {
+    obj.get(key)
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+fn dedup(mut list: Vec<String>) -> Vec<String> {
+    list.sort();
+    list.dedup();
+    list
+}
+
+/// Merge a global scope with a single node's own allow/deny entries into its
+/// effective, post-inheritance permission set.
+fn resolve_node_acl(
+    node: &str,
+    node_obj: &serde_json::Map<String, serde_json::Value>,
+    global: &serde_json::Map<String, serde_json::Value>,
+) -> ResolvedNodeAcl {
+    let mut allowed_commands = string_list(global, "allowCommands");
+    allowed_commands.extend(string_list(node_obj, "allowCommands"));
+
+    let mut allowed_capabilities = string_list(global, "capabilities");
+    allowed_capabilities.extend(string_list(node_obj, "capabilities"));
+
+    let mut denied = string_list(global, "deny");
+    denied.extend(string_list(node_obj, "deny"));
+    let denied = dedup(denied);
+
+    let retain = |entries: Vec<String>| -> Vec<String> {
+        entries
+            .into_iter()
+            .filter(|entry| is_wildcard(entry) || !denied.iter().any(|d| glob_match(d, entry)))
+            .collect()
+    };
+
+    ResolvedNodeAcl {
+        node: node.to_string(),
+        allowed_commands: dedup(retain(allowed_commands)),
+        allowed_capabilities: dedup(retain(allowed_capabilities)),
+        denied,
+    }
+}
+
 pub struct NodeScanner;
 
 impl Scanner for NodeScanner {
@@ -25,66 +166,88 @@ impl Scanner for NodeScanner {
         "Paired node and remote execution security"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
-        
+
         // Check for nodes configuration in raw config
         if let Some(nodes) = config.raw.get("nodes").and_then(|v| v.as_object()) {
-            // Check if any node has sensitive permissions
+            let empty = serde_json::Map::new();
+            let global = nodes
+                .get("global")
+                .and_then(|v| v.as_object())
+                .unwrap_or(&empty);
+
             for (node_name, node_config) in nodes {
-                if let Some(node_obj) = node_config.as_object() {
-                    // Check for unrestricted command access
-                    if let Some(allow_commands) = node_obj.get("allowCommands") {
-                        if allow_commands.is_array() {
-                            let commands = allow_commands.as_array().unwrap();
-                            // Check for wildcards or dangerous commands
-                            for cmd in commands {
-                                if let Some(cmd_str) = cmd.as_str() {
-                                    if cmd_str == "*" || cmd_str == "all" {
-                                        findings.push(Finding::new(
-                                            &format!("nodes.{}.unrestricted_commands", node_name),
-                                            self.name(),
-                                            Severity::Critical,
-                                            &format!("Node '{}' Has Unrestricted Commands", node_name),
-                                            &format!("Node '{}' allows all commands (*)", node_name),
-                                            "Any command can be executed on the node",
-                                            "Restrict allowCommands to specific needed commands",
-                                            &format!("nodes.{}.allowCommands", node_name),
-                                        ));
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
+                if node_name == "global" {
+                    continue;
+                }
+                let Some(node_obj) = node_config.as_object() else {
+                    continue;
+                };
 
-                    // Check for sensitive capabilities
-                    let sensitive_caps = ["camera", "screen", "contacts", "sms", "location"];
-                    let mut has_sensitive = Vec::new();
-                    
-                    if let Some(caps) = node_obj.get("capabilities").and_then(|v| v.as_array()) {
-                        for cap in caps {
-                            if let Some(cap_str) = cap.as_str() {
-                                if sensitive_caps.contains(&cap_str) {
-                                    has_sensitive.push(cap_str);
-                                }
-                            }
-                        }
-                    }
+                let acl = resolve_node_acl(node_name, node_obj, global);
+                let own_commands = string_list(node_obj, "allowCommands");
 
-                    if !has_sensitive.is_empty() {
+                if acl.has_wildcard_commands() {
+                    if own_commands.iter().any(|c| is_wildcard(c)) {
+                        findings.push(Finding::new(
+                            &format!("nodes.{}.unrestricted_commands", node_name),
+                            self.name(),
+                            Severity::Critical,
+                            &format!("Node '{}' Has Unrestricted Commands", node_name),
+                            &format!("Node '{}' allows all commands (*)", node_name),
+                            "Any command can be executed on the node",
+                            "Restrict allowCommands to specific needed commands",
+                            &format!("nodes.{}.allowCommands", node_name),
+                        ));
+                    } else {
                         findings.push(Finding::new(
-                            &format!("nodes.{}.sensitive_capabilities", node_name),
+                            &format!("nodes.{}.inherited_unrestricted_commands", node_name),
                             self.name(),
-                            Severity::Medium,
-                            &format!("Node '{}' Has Sensitive Capabilities", node_name),
-                            &format!("Node '{}' has access to: {}", node_name, has_sensitive.join(", ")),
-                            "Node can access sensitive device features",
-                            "Review if these capabilities are necessary",
-                            &format!("nodes.{}.capabilities", node_name),
+                            Severity::Critical,
+                            &format!("Node '{}' Inherits Unrestricted Commands", node_name),
+                            &format!(
+                                "Node '{}' has a restrictive-looking allowCommands list, but a global wildcard grants it every command anyway",
+                                node_name
+                            ),
+                            "The node's own allowlist is cosmetic; any command can still be executed",
+                            "Remove the global command wildcard or scope it below what any node needs",
+                            "nodes.global.allowCommands",
                         ));
                     }
                 }
+
+                let sensitive = acl.sensitive_capabilities();
+                if !sensitive.is_empty() {
+                    findings.push(Finding::new(
+                        &format!("nodes.{}.sensitive_capabilities", node_name),
+                        self.name(),
+                        Severity::Medium,
+                        &format!("Node '{}' Has Sensitive Capabilities", node_name),
+                        &format!("Node '{}' has access to: {}", node_name, sensitive.join(", ")),
+                        "Node can access sensitive device features",
+                        "Review if these capabilities are necessary",
+                        &format!("nodes.{}.capabilities", node_name),
+                    ));
+                }
+
+                let shadowed = acl.shadowed_denies();
+                if !shadowed.is_empty() {
+                    findings.push(Finding::new(
+                        &format!("nodes.{}.deny_shadowed_by_wildcard", node_name),
+                        self.name(),
+                        Severity::High,
+                        &format!("Node '{}' Deny List Is Shadowed", node_name),
+                        &format!(
+                            "Node '{}' denies {}, but a surviving wildcard grant still allows everything",
+                            node_name,
+                            shadowed.join(", ")
+                        ),
+                        "The deny entries give a false sense of restriction and have no practical effect",
+                        "Scope the wildcard allow down instead of relying on deny to narrow it",
+                        &format!("nodes.{}.deny", node_name),
+                    ));
+                }
             }
         }
 
@@ -4,16 +4,49 @@
 //! 
 //! Checks:
 //! - NPM package integrity (unpinned versions)
-//! - Plugin path containment
+//! - Plugin path containment (hardened against encoding/backslash tricks)
 //! - Lifecycle script execution risks
-//! - Known vulnerable dependencies
+//! - Known vulnerable dependencies (OSV advisory matching, online or offline)
 //! - Plugin-to-gateway privilege escalation
 //! - ClawHub path traversal vulnerabilities
 
+use crate::advisory::{self, OsvAdvisory};
 use crate::config::OpenClawConfig;
 use crate::models::{Finding, Severity};
+use crate::path_guard;
 use crate::scanner::Scanner;
 
+/// Trusted roots used to check that a skill URL or plugin install path
+/// can't escape into a sibling/parent location via traversal.
+const TRUSTED_SKILL_URL_ROOT: &str = "https://clawhub.dev/skills";
+const TRUSTED_PLUGIN_INSTALL_ROOT: &str = "/var/lib/openclaw/plugins";
+
+/// Consult `plugins.osv`/`skills.osv` config (`{"mode": "online"}` or
+/// `{"mode": "offline", "dbPath": "..."}`) to decide how to resolve
+/// advisories for a pinned `package@version`. Missing/malformed config
+/// means "don't look anything up" rather than an error.
+fn resolve_osv_advisories(
+    section: &serde_json::Map<String, serde_json::Value>,
+    ecosystem: &str,
+    package: &str,
+    version: &str,
+) -> Vec<OsvAdvisory> {
+    let Some(osv_cfg) = section.get("osv").and_then(|v| v.as_object()) else {
+        return Vec::new();
+    };
+
+    match osv_cfg.get("mode").and_then(|v| v.as_str()) {
+        Some("online") => advisory::query_online(ecosystem, package, version),
+        Some("offline") | None => osv_cfg
+            .get("dbPath")
+            .and_then(|v| v.as_str())
+            .and_then(|path| advisory::OfflineOsvDb::from_file(std::path::Path::new(path)).ok())
+            .map(|db| db.lookup(ecosystem, package, version))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
 pub struct PluginScanner;
 
 impl Scanner for PluginScanner {
@@ -25,9 +58,18 @@ impl Scanner for PluginScanner {
         "Plugin and extension security"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
-        
+
+        // Vetted/exempted sources (audits.toml) are consulted before we
+        // push noisy trust findings for them.
+        let audit_store = config
+            .raw
+            .get("auditStorePath")
+            .and_then(|v| v.as_str())
+            .and_then(|path| crate::audit_store::AuditStore::from_file(std::path::Path::new(path)).ok())
+            .unwrap_or_default();
+
         // Check for plugins configuration
         if let Some(plugins) = config.raw.get("plugins").and_then(|v| v.as_object()) {
             
@@ -35,23 +77,63 @@ impl Scanner for PluginScanner {
             if let Some(installed) = plugins.get("installed").and_then(|v| v.as_array()) {
                 for plugin in installed {
                     if let Some(plugin_obj) = plugin.as_object() {
+                        let name = plugin_obj.get("name").and_then(|v| v.as_str());
+                        let version = plugin_obj.get("version").and_then(|v| v.as_str());
+                        let trust_key = plugin_obj.get("source").and_then(|v| v.as_str()).or(name);
+                        let trusted = trust_key.is_some_and(|key| audit_store.is_trusted(key, version));
+
                         // Check version pinning
-                        if plugin_obj.get("version").is_none() {
-                            findings.push(Finding::new(
-                                "plugins.unpinned_version",
-                                self.name(),
-                                Severity::High,
-                                "Plugin Version Not Pinned",
-                                "A plugin does not have a pinned version",
-                                "Plugin could auto-update to vulnerable version",
-                                "Pin plugin versions to specific versions",
-                                "plugins.installed[].version",
-                            ));
+                        match version {
+                            None if trusted => {}
+                            None => {
+                                findings.push(Finding::new(
+                                    "plugins.unpinned_version",
+                                    self.name(),
+                                    Severity::High,
+                                    "Plugin Version Not Pinned",
+                                    "A plugin does not have a pinned version",
+                                    "Plugin could auto-update to vulnerable version",
+                                    "Pin plugin versions to specific versions",
+                                    "plugins.installed[].version",
+                                ));
+
+                                if let Some(name) = name {
+                                    findings.push(Finding::new(
+                                        "plugins.unauditable_unpinned",
+                                        self.name(),
+                                        Severity::Medium,
+                                        &format!("Cannot Audit Unpinned Plugin: {}", name),
+                                        &format!("Plugin '{}' has no pinned version, so known-vulnerability matching cannot run", name),
+                                        "A vulnerable version could be installed and upgraded without ever being checked",
+                                        "Pin the plugin to an explicit version so it can be matched against advisories",
+                                        "plugins.installed[].version",
+                                    ));
+                                }
+                            }
+                            Some(version) => {
+                                if let Some(name) = name {
+                                    for advisory in resolve_osv_advisories(plugins, "npm", name, version) {
+                                        findings.push(
+                                            Finding::new(
+                                                "plugins.vulnerable_dependency",
+                                                self.name(),
+                                                advisory::severity_from_cvss(advisory.cvss_score),
+                                                &format!("Vulnerable Plugin Dependency: {}@{}", name, version),
+                                                &advisory.summary,
+                                                "Plugin may be exploitable through a known, published vulnerability",
+                                                "Upgrade the plugin to a version that patches this advisory",
+                                                "plugins.installed[].version",
+                                            )
+                                            .with_cve(&advisory.id),
+                                        );
+                                    }
+                                }
+                            }
                         }
-                        
+
                         // Check for plugins from untrusted sources
                         if let Some(source) = plugin_obj.get("source").and_then(|v| v.as_str()) {
-                            if source.contains("github.com") && !source.contains("openclaw") {
+                            if source.contains("github.com") && !source.contains("openclaw") && !trusted {
                                 findings.push(Finding::new(
                                     "plugins.untrusted_source",
                                     self.name(),
@@ -64,6 +146,25 @@ impl Scanner for PluginScanner {
                                 ));
                             }
                         }
+
+                        // Check for path traversal in the plugin's install path
+                        if let Some(install_path) = plugin_obj.get("path").and_then(|v| v.as_str()) {
+                            if !path_guard::is_contained(TRUSTED_PLUGIN_INSTALL_ROOT, install_path) {
+                                findings.push(
+                                    Finding::new(
+                                        "plugins.path_traversal",
+                                        self.name(),
+                                        Severity::Critical,
+                                        "Plugin Path Traversal Detected",
+                                        &format!("Plugin install path escapes its trusted root: {}", install_path),
+                                        "Could install plugin files outside the intended directory",
+                                        "Use a plugin path contained within the trusted install root",
+                                        "plugins.installed[].path",
+                                    )
+                                    .with_cve("CVE-2026-XXXXX"),
+                                );
+                            }
+                        }
                     }
                 }
             }
@@ -90,9 +191,47 @@ impl Scanner for PluginScanner {
             if let Some(installed) = skills.get("installed").and_then(|v| v.as_array()) {
                 for skill in installed {
                     if let Some(skill_obj) = skill.as_object() {
+                        let name = skill_obj.get("name").and_then(|v| v.as_str());
+                        let version = skill_obj.get("version").and_then(|v| v.as_str());
+                        let trust_key = skill_obj.get("source").and_then(|v| v.as_str()).or(name);
+                        let trusted = trust_key.is_some_and(|key| audit_store.is_trusted(key, version));
+
+                        match (name, version) {
+                            (Some(name), Some(version)) => {
+                                for advisory in resolve_osv_advisories(skills, "npm", name, version) {
+                                    findings.push(
+                                        Finding::new(
+                                            "skills.vulnerable_dependency",
+                                            self.name(),
+                                            advisory::severity_from_cvss(advisory.cvss_score),
+                                            &format!("Vulnerable Skill Dependency: {}@{}", name, version),
+                                            &advisory.summary,
+                                            "Skill may be exploitable through a known, published vulnerability",
+                                            "Upgrade the skill to a version that patches this advisory",
+                                            "skills.installed[].version",
+                                        )
+                                        .with_cve(&advisory.id),
+                                    );
+                                }
+                            }
+                            (Some(name), None) => {
+                                findings.push(Finding::new(
+                                    "skills.unauditable_unpinned",
+                                    self.name(),
+                                    Severity::Medium,
+                                    &format!("Cannot Audit Unpinned Skill: {}", name),
+                                    &format!("Skill '{}' has no pinned version, so known-vulnerability matching cannot run", name),
+                                    "A vulnerable version could be installed without ever being checked",
+                                    "Pin the skill to an explicit version so it can be matched against advisories",
+                                    "skills.installed[].version",
+                                ));
+                            }
+                            (None, _) => {}
+                        }
+
                         // Check for path traversal in skill URL
                         if let Some(url) = skill_obj.get("url").and_then(|v| v.as_str()) {
-                            if url.contains("..") || url.contains("%2e%2e") {
+                            if !path_guard::is_contained(TRUSTED_SKILL_URL_ROOT, url) {
                                 findings.push(Finding::new(
                                     "skills.path_traversal",
                                     self.name(),
@@ -108,7 +247,7 @@ impl Scanner for PluginScanner {
                         
                         // Check for unsanitized skill sources
                         if let Some(source) = skill_obj.get("source").and_then(|v| v.as_str()) {
-                            if source != "clawhub" && !source.starts_with("https://") {
+                            if source != "clawhub" && !source.starts_with("https://") && !trusted {
                                 findings.push(Finding::new(
                                     "skills.untrusted_source",
                                     self.name(),
@@ -26,7 +26,7 @@ impl Scanner for PromptInjectionScanner {
         "Prompt injection chain detection"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         // NOTE: This is a configuration-based scanner. True prompt injection
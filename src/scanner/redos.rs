@@ -0,0 +1,227 @@
+//! ReDoS (Regular Expression Denial of Service) Scanner
+//!
+//! Priority: MEDIUM
+//!
+//! OpenClaw configs carry regex-like patterns in several places (tool
+//! allowlists, SSRF host rules, channel allowlists). This scanner walks the
+//! raw config looking for string values that look like regular expressions
+//! and flags ones that admit catastrophic backtracking:
+//!
+//! - *Exponential* risk: a repetition node (`*`, `+`, `{n,}`) whose body is
+//!   itself repeatable or nullable, e.g. `(a+)+`, `(a*)*`, or an alternation
+//!   inside a loop whose branches share a common first character, e.g.
+//!   `(a|ab)*` — these admit two distinct derivations of the same substring.
+//! - *Polynomial* risk: two adjacent quantifiers ranging over overlapping
+//!   character classes, e.g. `\d+\d+`, `.*.*`.
+//!
+//! Unparseable patterns are reported as `Info`, never panicked on.
+
+use regex_syntax::ast::{Ast, RepetitionKind, RepetitionRange};
+use regex_syntax::ast::parse::Parser;
+
+use crate::config::OpenClawConfig;
+use crate::models::{Finding, Severity};
+use crate::scanner::Scanner;
+
+pub struct RedosScanner;
+
+impl Scanner for RedosScanner {
+    fn name(&self) -> &str {
+        "redos"
+    }
+
+    fn description(&self) -> &str {
+        "Catastrophic backtracking detection in config-supplied regex patterns"
+    }
+
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut candidates = Vec::new();
+        collect_regex_candidates(&config.raw, "$", &mut candidates);
+
+        for (config_path, pattern) in candidates {
+            match Parser::new().parse(&pattern) {
+                Ok(ast) => {
+                    if has_exponential_blowup(&ast) {
+                        findings.push(Finding::new(
+                            "redos.exponential_backtracking",
+                            self.name(),
+                            Severity::High,
+                            "Catastrophic Backtracking Pattern",
+                            &format!("Pattern '{}' contains a nested/nullable repetition that admits exponential backtracking", pattern),
+                            "A crafted input can make matching this pattern take exponential time, hanging the process",
+                            "Anchor the pattern or rewrite the nested group as a possessive/atomic match",
+                            &config_path,
+                        ));
+                    } else if has_polynomial_blowup(&ast) {
+                        findings.push(Finding::new(
+                            "redos.polynomial_backtracking",
+                            self.name(),
+                            Severity::Medium,
+                            "Polynomial Backtracking Pattern",
+                            &format!("Pattern '{}' has adjacent quantifiers over overlapping character classes", pattern),
+                            "A crafted input can make matching this pattern take polynomial time",
+                            "Merge the adjacent quantifiers or narrow the character classes so they don't overlap",
+                            &config_path,
+                        ));
+                    }
+                }
+                Err(_) => {
+                    findings.push(Finding::new(
+                        "redos.unparseable_pattern",
+                        self.name(),
+                        Severity::Info,
+                        "Unparseable Regex Pattern",
+                        &format!("Pattern '{}' could not be parsed as a regular expression", pattern),
+                        "The pattern may fail at runtime or behave unexpectedly",
+                        "Verify the pattern is valid regex syntax",
+                        &config_path,
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+/// Recursively walk the raw config, collecting (config_path, value) pairs for
+/// string values that look like they're meant to be regexes rather than
+/// plain literals (they contain at least one regex metacharacter).
+fn collect_regex_candidates(value: &serde_json::Value, path: &str, out: &mut Vec<(String, String)>) {
+    match value {
+        serde_json::Value::String(s) => {
+            if looks_like_regex(s) {
+                out.push((path.to_string(), s.clone()));
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_regex_candidates(item, &format!("{}[{}]", path, i), out);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (key, item) in map {
+                collect_regex_candidates(item, &format!("{}.{}", path, key), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn looks_like_regex(s: &str) -> bool {
+    s.len() >= 2 && s.chars().any(|c| "*+{}().[]|\\^$".contains(c))
+}
+
+/// True if any repetition node's inner body is itself repeatable or
+/// nullable (e.g. `(a+)+`, `(a*)*`), or is an alternation whose branches
+/// share a common first-character set inside a loop (e.g. `(a|ab)*`).
+fn has_exponential_blowup(ast: &Ast) -> bool {
+    match ast {
+        Ast::Repetition(rep) => {
+            if matches!(
+                rep.op.kind,
+                RepetitionKind::ZeroOrMore | RepetitionKind::OneOrMore | RepetitionKind::Range(RepetitionRange::AtLeast(_))
+            ) {
+                if is_repeatable_or_nullable(&rep.ast) {
+                    return true;
+                }
+            }
+            has_exponential_blowup(&rep.ast)
+        }
+        Ast::Group(group) => has_exponential_blowup(&group.ast),
+        Ast::Concat(concat) => concat.asts.iter().any(has_exponential_blowup),
+        Ast::Alternation(alt) => alt.asts.iter().any(has_exponential_blowup),
+        _ => false,
+    }
+}
+
+/// Does this sub-pattern itself contain a nested repetition (so the outer
+/// loop re-derives the same text two different ways), or an alternation
+/// whose branches share a first character (also re-derivable)?
+fn is_repeatable_or_nullable(ast: &Ast) -> bool {
+    match ast {
+        Ast::Repetition(_) => true,
+        Ast::Group(group) => is_repeatable_or_nullable(&group.ast),
+        Ast::Concat(concat) => concat.asts.iter().any(is_repeatable_or_nullable),
+        Ast::Alternation(alt) => {
+            alt.asts.iter().any(is_repeatable_or_nullable) || alternatives_share_prefix(alt)
+        }
+        _ => false,
+    }
+}
+
+fn alternatives_share_prefix(alt: &regex_syntax::ast::Alternation) -> bool {
+    let firsts: Vec<Option<char>> = alt.asts.iter().map(first_literal_char).collect();
+    for i in 0..firsts.len() {
+        for j in (i + 1)..firsts.len() {
+            if let (Some(a), Some(b)) = (firsts[i], firsts[j]) {
+                if a == b {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+fn first_literal_char(ast: &Ast) -> Option<char> {
+    match ast {
+        Ast::Literal(lit) => Some(lit.c),
+        Ast::Concat(concat) => concat.asts.first().and_then(first_literal_char),
+        Ast::Group(group) => first_literal_char(&group.ast),
+        _ => None,
+    }
+}
+
+/// True if a concat sequence contains two adjacent quantified nodes whose
+/// character classes overlap (e.g. `\d+\d+`, `.*.*`).
+fn has_polynomial_blowup(ast: &Ast) -> bool {
+    match ast {
+        Ast::Concat(concat) => {
+            for window in concat.asts.windows(2) {
+                if let (Some(a), Some(b)) = (quantified_class_kind(&window[0]), quantified_class_kind(&window[1])) {
+                    if a == b || a == CharKind::Any || b == CharKind::Any {
+                        return true;
+                    }
+                }
+            }
+            concat.asts.iter().any(has_polynomial_blowup)
+        }
+        Ast::Group(group) => has_polynomial_blowup(&group.ast),
+        Ast::Alternation(alt) => alt.asts.iter().any(has_polynomial_blowup),
+        Ast::Repetition(rep) => has_polynomial_blowup(&rep.ast),
+        _ => false,
+    }
+}
+
+#[derive(PartialEq)]
+enum CharKind {
+    Any,
+    Digit,
+    Word,
+    Space,
+}
+
+/// Classify a quantified node's underlying character class, if it is one we
+/// recognize as potentially overlapping with an adjacent quantified class.
+fn quantified_class_kind(ast: &Ast) -> Option<CharKind> {
+    let rep = match ast {
+        Ast::Repetition(rep)
+            if matches!(rep.op.kind, RepetitionKind::ZeroOrMore | RepetitionKind::OneOrMore | RepetitionKind::Range(_)) =>
+        {
+            rep
+        }
+        _ => return None,
+    };
+
+    match &*rep.ast {
+        Ast::Dot(_) => Some(CharKind::Any),
+        Ast::ClassPerl(class) => match class.kind {
+            regex_syntax::ast::ClassPerlKind::Digit => Some(CharKind::Digit),
+            regex_syntax::ast::ClassPerlKind::Word => Some(CharKind::Word),
+            regex_syntax::ast::ClassPerlKind::Space => Some(CharKind::Space),
+        },
+        _ => None,
+    }
+}
@@ -28,7 +28,7 @@ impl Scanner for SandboxScanner {
         "Sandbox configuration and container isolation"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
         let sb = &config.sandbox;
         let tools = &config.tools;
@@ -0,0 +1,270 @@
+//! OpenClaw Config Schema Scanner
+//!
+//! Priority: HIGH
+//!
+//! Checks:
+//! - Unknown/misspelled top-level and nested keys, within the blocks whose
+//!   full shape is known
+//! - Wrong value types for known keys
+//!
+//! Runs against `config.raw` directly (ahead of the semantic scanners) so a
+//! typo like `sandbox.workspaceAcess` is caught before it silently falls
+//! back to a "safe" default and hides a real exposure.
+
+use jsonschema::JSONSchema;
+use once_cell::sync::Lazy;
+
+use crate::config::OpenClawConfig;
+use crate::models::{Finding, Severity};
+use crate::scanner::Scanner;
+
+/// Bundled JSON Schema describing the OpenClaw config shape we understand.
+/// Every modeled object whose full key set is actually known across the
+/// codebase (grep for every `.get("...")` against `config.raw` plus every
+/// typed `config.rs` field) sets `additionalProperties: false`, so a
+/// misspelled or renamed key (e.g. `sandbox.workspaceAcess`) is flagged
+/// instead of silently passing through. Blocks with a dynamic, open-ended
+/// key set - `channels` (channel names), `nodes` (node names plus
+/// `global`), `plugins`/`skills` (installed-entry objects with their own
+/// extra metadata), `extensions` - stay permissive instead, the same way
+/// `channels` always has. None of the objects carry a `required` list:
+/// every field in `config.rs` is `Option`/`#[serde(default)]`, so a config
+/// that simply omits a whole block (down to the top level) is using
+/// defaults, not an error.
+static CONFIG_SCHEMA: Lazy<serde_json::Value> = Lazy::new(|| {
+    serde_json::json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "version": { "type": "string" },
+            "openclaw": { "type": "object" },
+            "auditStorePath": { "type": "string" },
+            "gateway": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "mode": { "type": "string" },
+                    "bind": { "type": "string" },
+                    "port": { "type": "integer" },
+                    "auth": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "mode": { "type": "string" },
+                            "token": { "type": "string" }
+                        }
+                    },
+                    "tailscale": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "funnel": { "type": "boolean" } }
+                    },
+                    "trustedProxies": {
+                        "type": "array",
+                        "items": { "type": "string" }
+                    },
+                    "discovery": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "mdns": {
+                                "type": "object",
+                                "additionalProperties": false,
+                                "properties": { "mode": { "type": "string" } }
+                            }
+                        }
+                    },
+                    "controlUi": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "allowedOrigins": { "type": "array", "items": { "type": "string" } }
+                        }
+                    },
+                    "http": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "noAuth": { "type": "boolean" } }
+                    },
+                    "tls": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "enabled": { "type": "boolean" },
+                            "certPath": { "type": "string" },
+                            "keyPath": { "type": "string" },
+                            "minVersion": { "type": "string" }
+                        }
+                    },
+                    "headers": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "contentSecurityPolicy": { "type": "string" },
+                            "xContentTypeOptions": { "type": "string" },
+                            "referrerPolicy": { "type": "string" },
+                            "xFrameOptions": { "type": "string" },
+                            "permissionsPolicy": { "type": "string" }
+                        }
+                    }
+                }
+            },
+            "tools": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "profile": { "type": "string" },
+                    "deny": { "type": "array", "items": { "type": "string" } },
+                    "exec": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "host": { "type": "string" },
+                            "security": { "type": "string" },
+                            "ask": { "type": "string" },
+                            "safeBins": { "type": "array", "items": { "type": "string" } },
+                            "allowNodeExec": { "type": "boolean" }
+                        }
+                    },
+                    "elevated": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "enabled": { "type": "boolean" } }
+                    },
+                    "fs": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "workspaceOnly": { "type": "boolean" } }
+                    },
+                    "webFetch": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "ssrfPolicy": { "type": "string" } }
+                    },
+                    "webSearch": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "ssrfPolicy": { "type": "string" } }
+                    },
+                    "confirm": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "pattern": { "type": "string" } }
+                    },
+                    "browser": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "relay": {
+                                "type": "object",
+                                "additionalProperties": false,
+                                "properties": { "bind": { "type": "string" } }
+                            },
+                            "cdp": {
+                                "type": "object",
+                                "additionalProperties": false,
+                                "properties": {
+                                    "enabled": { "type": "boolean" },
+                                    "bind": { "type": "string" }
+                                }
+                            },
+                            "downloadDir": { "type": "string" },
+                            "profile": { "type": "string" }
+                        }
+                    },
+                    "capabilities": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "additionalProperties": false,
+                            "properties": {
+                                "permission": { "type": "string" },
+                                "tool": { "type": "string" },
+                                "scope": { "type": "array", "items": { "type": "string" } }
+                            }
+                        }
+                    }
+                }
+            },
+            "agents": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "defaults": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "sandbox": {
+                                "type": "object",
+                                "additionalProperties": false,
+                                "properties": {
+                                    "mode": { "type": "string" },
+                                    "workspaceAccess": { "type": "string" },
+                                    "scope": { "type": "string" }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "session": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "dmScope": { "type": "string" }
+                }
+            },
+            "channels": { "type": "object" },
+            "nodes": { "type": "object" },
+            "plugins": { "type": "object" },
+            "skills": { "type": "object" },
+            "extensions": { "type": "object" },
+            "memory": { "type": "object" }
+        }
+    })
+});
+
+pub struct SchemaScanner;
+
+impl Scanner for SchemaScanner {
+    fn name(&self) -> &str {
+        "schema"
+    }
+
+    fn description(&self) -> &str {
+        "JSON Schema validation of the raw OpenClaw config"
+    }
+
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let compiled = match JSONSchema::compile(&CONFIG_SCHEMA) {
+            Ok(c) => c,
+            Err(_) => return findings,
+        };
+
+        if let Err(errors) = compiled.validate(&config.raw) {
+            for error in errors {
+                let pointer = error.instance_path.to_string();
+                let config_path = if pointer.is_empty() {
+                    "$".to_string()
+                } else {
+                    pointer.trim_start_matches('/').replace('/', ".")
+                };
+
+                findings.push(Finding::new(
+                    "schema.validation_error",
+                    self.name(),
+                    Severity::Medium,
+                    "Config Fails Schema Validation",
+                    &format!("{}", error),
+                    "A misspelled or mistyped key can silently fall back to a permissive default",
+                    "Fix the offending key/value so it matches the expected config shape",
+                    &config_path,
+                ));
+            }
+        }
+
+        findings
+    }
+}
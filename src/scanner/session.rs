@@ -24,7 +24,7 @@ impl Scanner for SessionScanner {
         "Session handling and identity management"
     }
 
-    fn scan(&self, config: &OpenClawConfig) -> Vec<Finding> {
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
         let mut findings = Vec::new();
         let sess = &config.session;
 
@@ -0,0 +1,197 @@
+//! SSRF Policy & Trusted-Proxy Scanner
+//!
+//! Priority: HIGH
+//!
+//! Checks:
+//! - web_fetch/web_search SSRF policy values outside the known-safe set
+//! - The injection -> fetch -> exec/browser chain when SSRF protection is
+//!   fully disabled
+//! - trusted_proxies entries overlapping loopback/link-local/RFC1918 space
+//!   (including the 169.254.169.254 cloud metadata address)
+//! - Web tools enabled with no SSRF policy configured at all
+
+use std::net::Ipv4Addr;
+
+use crate::config::OpenClawConfig;
+use crate::models::{Finding, Severity};
+use crate::scanner::Scanner;
+
+const SAFE_POLICIES: &[&str] = &["deny", "allowlist", "filtered"];
+const PERMISSIVE_POLICIES: &[&str] = &["off", "allow-all"];
+
+/// An IPv4 CIDR range, parsed from a bare IP (implicit `/32`) or
+/// `host/prefix` notation. A missing/empty prefix is also treated as `/32`.
+struct Cidr {
+    base: u32,
+    prefix: u8,
+}
+
+impl Cidr {
+    fn parse(value: &str) -> Option<Self> {
+        let (ip_part, prefix_part) = match value.split_once('/') {
+            Some((ip, prefix)) if !prefix.trim().is_empty() => (ip, prefix),
+            Some((ip, _)) => (ip, "32"),
+            None => (value, "32"),
+        };
+        let ip: Ipv4Addr = ip_part.trim().parse().ok()?;
+        let prefix: u8 = prefix_part.trim().parse().ok()?;
+        if prefix > 32 {
+            return None;
+        }
+        Some(Cidr { base: u32::from(ip), prefix })
+    }
+
+    fn overlaps(&self, other: &Cidr) -> bool {
+        let shared_prefix = self.prefix.min(other.prefix);
+        let mask = if shared_prefix == 0 {
+            0
+        } else {
+            u32::MAX << (32 - shared_prefix)
+        };
+        (self.base & mask) == (other.base & mask)
+    }
+}
+
+fn reserved_ranges() -> [(&'static str, &'static str); 5] {
+    [
+        ("loopback (127.0.0.0/8)", "127.0.0.0/8"),
+        ("link-local, incl. the cloud metadata address (169.254.0.0/16)", "169.254.0.0/16"),
+        ("RFC1918 private space (10.0.0.0/8)", "10.0.0.0/8"),
+        ("RFC1918 private space (172.16.0.0/12)", "172.16.0.0/12"),
+        ("RFC1918 private space (192.168.0.0/16)", "192.168.0.0/16"),
+    ]
+}
+
+pub struct SsrfScanner;
+
+impl Scanner for SsrfScanner {
+    fn name(&self) -> &str {
+        "ssrf"
+    }
+
+    fn description(&self) -> &str {
+        "SSRF policy and trusted-proxy range validation"
+    }
+
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let tools = &config.tools;
+
+        let tools_obj = config.raw.get("tools").and_then(|v| v.as_object());
+        let web_tools_enabled = tools_obj
+            .map(|t| t.contains_key("webFetch") || t.contains_key("webSearch"))
+            .unwrap_or(false);
+        let exec_enabled = tools.exec_host.is_some()
+            || tools_obj.map(|t| t.contains_key("exec")).unwrap_or(false);
+        let browser_enabled = tools_obj.map(|t| t.contains_key("browser")).unwrap_or(false);
+
+        for (label, policy, config_path) in [
+            ("web_fetch", &tools.web_fetch_ssrf_policy, "tools.webFetch.ssrfPolicy"),
+            ("web_search", &tools.web_search_ssrf_policy, "tools.webSearch.ssrfPolicy"),
+        ] {
+            match policy.as_deref() {
+                None => {
+                    if web_tools_enabled {
+                        findings.push(Finding::new(
+                            &format!("ssrf.{}_policy_missing", label),
+                            self.name(),
+                            Severity::Medium,
+                            &format!("{} Has No SSRF Policy", label),
+                            &format!("Web tools are enabled but {} has no ssrfPolicy set, defaulting to permissive", label),
+                            "Internal network resources may be reachable through prompt-injected fetches",
+                            &format!("Set {} to 'deny', 'allowlist', or 'filtered'", config_path),
+                            config_path,
+                        ));
+                    }
+                }
+                Some(value) if PERMISSIVE_POLICIES.contains(&value) => {
+                    let chained = exec_enabled || browser_enabled;
+                    findings.push(Finding::new(
+                        &format!("ssrf.{}_policy_permissive", label),
+                        self.name(),
+                        if chained { Severity::Critical } else { Severity::High },
+                        &format!("{} SSRF Policy Is Permissive", label),
+                        &format!(
+                            "{} ssrfPolicy is '{}'{}",
+                            label,
+                            value,
+                            if chained { " and tools.exec/tools.browser are also enabled" } else { "" }
+                        ),
+                        if chained {
+                            "A prompt-injected fetch could reach internal services and chain into command execution or browser control"
+                        } else {
+                            "May allow access to internal network resources"
+                        },
+                        &format!("Set {} to 'deny', 'allowlist', or 'filtered'", config_path),
+                        config_path,
+                    ));
+                }
+                Some(value) if !SAFE_POLICIES.contains(&value) => {
+                    findings.push(Finding::new(
+                        &format!("ssrf.{}_policy_unknown", label),
+                        self.name(),
+                        Severity::Medium,
+                        &format!("{} SSRF Policy Value Is Unrecognized", label),
+                        &format!("{} ssrfPolicy is '{}', which is not a known-safe mode", label, value),
+                        "An unrecognized policy value may be ignored and fall back to permissive behavior",
+                        &format!("Set {} to 'deny', 'allowlist', or 'filtered'", config_path),
+                        config_path,
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
+        if let Some(proxies) = &config.gateway.trusted_proxies {
+            for proxy in proxies {
+                if proxy.trim() == "::1" {
+                    findings.push(Finding::new(
+                        "ssrf.trusted_proxy_reserved_range",
+                        self.name(),
+                        Severity::High,
+                        "Trusted Proxy Overlaps Loopback",
+                        &format!("trusted_proxies entry '{}' is the IPv6 loopback address", proxy),
+                        "A local process could spoof the trusted-proxy client-identity headers",
+                        "Remove loopback from gateway.trustedProxies",
+                        "gateway.trustedProxies",
+                    ));
+                    continue;
+                }
+
+                match Cidr::parse(proxy) {
+                    Some(candidate) => {
+                        for (label, reserved) in reserved_ranges() {
+                            let reserved = Cidr::parse(reserved).expect("reserved ranges are valid");
+                            if candidate.overlaps(&reserved) {
+                                findings.push(Finding::new(
+                                    "ssrf.trusted_proxy_reserved_range",
+                                    self.name(),
+                                    Severity::High,
+                                    "Trusted Proxy Overlaps Reserved Range",
+                                    &format!("trusted_proxies entry '{}' overlaps {}", proxy, label),
+                                    "Trusting this range lets an attacker spoof client identity or reach internal services",
+                                    "Scope gateway.trustedProxies to the actual reverse proxy's address only",
+                                    "gateway.trustedProxies",
+                                ));
+                            }
+                        }
+                    }
+                    None => {
+                        findings.push(Finding::new(
+                            "ssrf.trusted_proxy_unparseable",
+                            self.name(),
+                            Severity::Info,
+                            "Trusted Proxy Entry Could Not Be Parsed",
+                            &format!("trusted_proxies entry '{}' is not a valid IP or CIDR", proxy),
+                            "This entry can't be validated against reserved ranges",
+                            "Use a bare IP or host/prefix CIDR notation",
+                            "gateway.trustedProxies",
+                        ));
+                    }
+                }
+            }
+        }
+
+        findings
+    }
+}
@@ -0,0 +1,123 @@
+//! Gateway TLS Scanner
+//!
+//! Priority: HIGH
+//!
+//! Checks:
+//! - Non-loopback gateway bind with TLS disabled (not already covered by
+//!   GatewayScanner's public+noAuth+noTLS chain finding)
+//! - `tls.minVersion` below 1.2
+//! - Cert/key paths that are world-readable or sit inside the agent workspace
+
+use std::path::Path;
+
+use crate::config::OpenClawConfig;
+use crate::models::{Finding, Severity};
+use crate::path_guard;
+use crate::scanner::Scanner;
+
+/// Where agent-writable files live; a private key under here is reachable by
+/// anything that can get the agent to read or exfiltrate a workspace file.
+const AGENT_WORKSPACE_ROOT: &str = "/var/lib/openclaw/workspace";
+
+fn min_version_too_low(min_version: &str) -> bool {
+    matches!(min_version, "1.0" | "1.1" | "SSLv3" | "SSLv2")
+}
+
+#[cfg(unix)]
+fn is_world_readable(path: &str) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|meta| meta.permissions().mode() & 0o004 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_world_readable(_path: &str) -> bool {
+    false
+}
+
+pub struct TlsScanner;
+
+impl Scanner for TlsScanner {
+    fn name(&self) -> &str {
+        "tls"
+    }
+
+    fn description(&self) -> &str {
+        "Gateway transport security (TLS) configuration"
+    }
+
+    fn scan(&self, config: &OpenClawConfig, _kb: &crate::knowledge::KnowledgeBase) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let gw = &config.gateway;
+        let tls = &gw.tls;
+
+        let bind_public = gw.bind.as_deref() == Some("0.0.0.0") || gw.bind.as_deref() == Some("0.0.0.0:0");
+        let no_auth = gw.http_no_auth == Some(true);
+        let non_loopback = gw.bind.as_deref() != Some("loopback") && gw.bind.is_some();
+        let tls_enabled = tls.enabled == Some(true);
+
+        // Already reported as part of GatewayScanner's chain finding when
+        // bind is public and auth is also off; don't double-report it here.
+        if non_loopback && !tls_enabled && !(bind_public && no_auth) {
+            findings.push(Finding::new(
+                "tls.gateway_plaintext",
+                self.name(),
+                Severity::Critical,
+                "Gateway Reachable Without TLS",
+                "Gateway is bound to a non-loopback address with TLS disabled",
+                "Tokens and request/response bodies travel in plaintext on the network",
+                "Enable gateway.tls with a valid cert/key pair",
+                "gateway.tls.enabled",
+            ));
+        }
+
+        if let Some(min_version) = &tls.min_version {
+            if min_version_too_low(min_version) {
+                findings.push(Finding::new(
+                    "tls.min_version_weak",
+                    self.name(),
+                    Severity::High,
+                    "TLS Minimum Version Below 1.2",
+                    &format!("gateway.tls.minVersion is '{}'", min_version),
+                    "Older TLS versions have known cryptographic weaknesses",
+                    "Set gateway.tls.minVersion to '1.2' or '1.3'",
+                    "gateway.tls.minVersion",
+                ));
+            }
+        }
+
+        for (field, label, path) in [
+            ("certPath", "Certificate", &tls.cert_path),
+            ("keyPath", "Private Key", &tls.key_path),
+        ] {
+            let Some(path) = path else { continue };
+
+            if path_guard::is_contained(AGENT_WORKSPACE_ROOT, path) {
+                findings.push(Finding::new(
+                    "tls.key_in_workspace",
+                    self.name(),
+                    Severity::High,
+                    &format!("TLS {} Sits Inside the Agent Workspace", label),
+                    &format!("gateway.tls.{} '{}' resolves inside the agent workspace", field, path),
+                    "Injected file access from a compromised agent session could exfiltrate the private key",
+                    &format!("Move the {} outside the agent workspace", label.to_lowercase()),
+                    &format!("gateway.tls.{}", field),
+                ));
+            } else if is_world_readable(path) {
+                findings.push(Finding::new(
+                    "tls.key_world_readable",
+                    self.name(),
+                    Severity::High,
+                    &format!("TLS {} Is World-Readable", label),
+                    &format!("gateway.tls.{} '{}' is readable by any local user", field, path),
+                    "Any local user or process can read the private key material",
+                    &format!("chmod 600 {}", Path::new(path).display()),
+                    &format!("gateway.tls.{}", field),
+                ));
+            }
+        }
+
+        findings
+    }
+}
@@ -0,0 +1,123 @@
+//! Live Config Watch Mode
+//!
+//! Polls a config file for modifications and re-runs the full scanner suite
+//! on each change, emitting only the delta against the previous scan -
+//! findings that newly appeared, findings that were resolved, and findings
+//! whose severity changed - so an operator editing gateway/sandbox/tools
+//! config gets immediate feedback on whether their edit introduced or
+//! cleared a risk, instead of re-reading a full report every time.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::config::OpenClawConfig;
+use crate::knowledge::KnowledgeBase;
+use crate::models::{Finding, Severity};
+use crate::scanner::get_all_scanners;
+
+/// A finding whose severity changed between two consecutive scans.
+#[derive(Debug, Clone)]
+pub struct SeverityChange {
+    pub id: String,
+    pub from: Severity,
+    pub to: Severity,
+}
+
+/// The difference between two consecutive scans of the same config.
+#[derive(Debug, Clone, Default)]
+pub struct ScanDiff {
+    pub new_findings: Vec<Finding>,
+    pub resolved_findings: Vec<Finding>,
+    pub severity_changes: Vec<SeverityChange>,
+}
+
+impl ScanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new_findings.is_empty()
+            && self.resolved_findings.is_empty()
+            && self.severity_changes.is_empty()
+    }
+}
+
+fn run_once(config_path: &Path) -> Result<Vec<Finding>, String> {
+    let config = OpenClawConfig::from_file(config_path)?;
+    let kb = KnowledgeBase::default();
+    let mut findings = Vec::new();
+    for scanner in get_all_scanners() {
+        findings.extend(scanner.scan(&config, &kb));
+    }
+    Ok(findings)
+}
+
+/// Diff `current` against the id-keyed `previous` scan.
+fn diff(previous: &HashMap<String, Finding>, current: &[Finding]) -> ScanDiff {
+    let mut result = ScanDiff::default();
+    let current_ids: std::collections::HashSet<&str> =
+        current.iter().map(|f| f.id.as_str()).collect();
+
+    for finding in current {
+        match previous.get(&finding.id) {
+            None => result.new_findings.push(finding.clone()),
+            Some(prev) if prev.severity != finding.severity => {
+                result.severity_changes.push(SeverityChange {
+                    id: finding.id.clone(),
+                    from: prev.severity,
+                    to: finding.severity,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    for (id, prev) in previous {
+        if !current_ids.contains(id.as_str()) {
+            result.resolved_findings.push(prev.clone());
+        }
+    }
+
+    result
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
+
+/// Poll `config_path` every `interval`, re-scanning on modification and
+/// invoking `on_change` with the delta against the previous scan whenever
+/// the delta is non-empty. Runs until the process is killed - there is no
+/// built-in stop condition.
+pub fn watch(
+    config_path: &Path,
+    interval: Duration,
+    mut on_change: impl FnMut(ScanDiff),
+) -> Result<(), String> {
+    let mut last_modified = modified_time(config_path);
+    let mut previous: HashMap<String, Finding> = run_once(config_path)?
+        .into_iter()
+        .map(|f| (f.id.clone(), f))
+        .collect();
+
+    loop {
+        std::thread::sleep(interval);
+
+        let current_modified = modified_time(config_path);
+        if current_modified == last_modified {
+            continue;
+        }
+        last_modified = current_modified;
+
+        let current = match run_once(config_path) {
+            Ok(findings) => findings,
+            Err(_) => continue,
+        };
+
+        let delta = diff(&previous, &current);
+        previous = current.into_iter().map(|f| (f.id.clone(), f)).collect();
+
+        if !delta.is_empty() {
+            on_change(delta);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Scan Observability
+//!
+//! Optional instrumentation for running Dino-AISS as a long-lived service or
+//! in CI: a span per `Scanner::scan` invocation (duration + finding count),
+//! a findings counter keyed by [`Severity`], and end-of-scan gauges for
+//! `health_score` and `scan_time_seconds`. Disabled by default and a no-op
+//! when disabled or unconfigured, so the core library stays dependency-light
+//! unless an operator opts in with an OTLP endpoint.
+//!
+//! Payloads are a simplified OTLP/HTTP-JSON envelope (one span/metric per
+//! call) rather than the full `opentelemetry` SDK and its protobuf/gRPC
+//! stack - close enough for an OTLP HTTP receiver to ingest, not a
+//! spec-complete exporter.
+
+use std::time::Duration;
+
+use crate::models::Severity;
+
+#[derive(Debug, Clone, Default)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    pub otlp_endpoint: Option<String>,
+}
+
+/// Observability handle threaded through a scan. Every method is a no-op
+/// unless `enabled` is true and an `otlp_endpoint` is configured; export
+/// failures are swallowed since telemetry must never fail a scan.
+#[derive(Debug, Clone, Default)]
+pub struct Telemetry {
+    config: TelemetryConfig,
+}
+
+impl Telemetry {
+    pub fn new(config: TelemetryConfig) -> Self {
+        Self { config }
+    }
+
+    fn endpoint(&self) -> Option<&str> {
+        if self.config.enabled {
+            self.config.otlp_endpoint.as_deref()
+        } else {
+            None
+        }
+    }
+
+    fn export(&self, payload: serde_json::Value) {
+        let Some(endpoint) = self.endpoint() else {
+            return;
+        };
+        let _ = reqwest::blocking::Client::new()
+            .post(endpoint)
+            .json(&payload)
+            .send();
+    }
+
+    /// Record one completed `Scanner::scan` invocation as a span.
+    pub fn record_scan_span(&self, scanner_name: &str, duration: Duration, finding_count: usize) {
+        self.export(serde_json::json!({
+            "name": format!("scanner.scan.{}", scanner_name),
+            "kind": "span",
+            "attributes": {
+                "scanner.name": scanner_name,
+                "scanner.duration_ms": duration.as_millis() as u64,
+                "scanner.finding_count": finding_count,
+            },
+        }));
+    }
+
+    /// Increment the `dino_aiss.findings_total` counter for one finding.
+    pub fn record_finding(&self, severity: Severity) {
+        self.export(serde_json::json!({
+            "name": "dino_aiss.findings_total",
+            "kind": "counter",
+            "value": 1,
+            "attributes": { "severity": severity.as_str() },
+        }));
+    }
+
+    /// Emit end-of-scan gauges for overall health score and scan duration.
+    pub fn record_scan_summary(&self, health_score: i32, scan_time_seconds: f64) {
+        self.export(serde_json::json!({
+            "name": "dino_aiss.health_score",
+            "kind": "gauge",
+            "value": health_score,
+        }));
+        self.export(serde_json::json!({
+            "name": "dino_aiss.scan_time_seconds",
+            "kind": "gauge",
+            "value": scan_time_seconds,
+        }));
+    }
+}
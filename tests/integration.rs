@@ -2,6 +2,7 @@
 //! Run with: cargo test --test integration
 
 use dino_aiss::{OpenClawConfig, GatewayScanner, SandboxScanner, ToolsScanner, CredentialsScanner, PluginScanner, Scanner};
+use dino_aiss::knowledge::KnowledgeBase;
 
 #[test]
 fn test_gateway_auth_none_critical() {
@@ -13,7 +14,8 @@ fn test_gateway_auth_none_critical() {
     });
     let config = OpenClawConfig::from_dict(config_json).unwrap();
     let scanner = GatewayScanner;
-    let findings = scanner.scan(&config);
+    let kb = KnowledgeBase::default();
+    let findings = scanner.scan(&config, &kb);
     
     assert!(!findings.is_empty());
 }
@@ -28,7 +30,8 @@ fn test_gateway_bind_public_critical() {
     });
     let config = OpenClawConfig::from_dict(config_json).unwrap();
     let scanner = GatewayScanner;
-    let findings = scanner.scan(&config);
+    let kb = KnowledgeBase::default();
+    let findings = scanner.scan(&config, &kb);
     
     assert!(!findings.is_empty());
 }
@@ -40,7 +43,8 @@ fn test_sandbox_mode_off_critical() {
     });
     let config = OpenClawConfig::from_dict(config_json).unwrap();
     let scanner = SandboxScanner;
-    let findings = scanner.scan(&config);
+    let kb = KnowledgeBase::default();
+    let findings = scanner.scan(&config, &kb);
     
     assert!(!findings.is_empty());
 }
@@ -52,7 +56,8 @@ fn test_tools_elevated_enabled() {
     });
     let config = OpenClawConfig::from_dict(config_json).unwrap();
     let scanner = ToolsScanner;
-    let findings = scanner.scan(&config);
+    let kb = KnowledgeBase::default();
+    let findings = scanner.scan(&config, &kb);
     
     assert!(!findings.is_empty());
 }
@@ -64,7 +69,8 @@ fn test_credentials_token_in_config() {
     });
     let config = OpenClawConfig::from_dict(config_json).unwrap();
     let scanner = CredentialsScanner;
-    let findings = scanner.scan(&config);
+    let kb = KnowledgeBase::default();
+    let findings = scanner.scan(&config, &kb);
     
     assert!(!findings.is_empty());
 }
@@ -76,7 +82,8 @@ fn test_plugins_allow_unverified() {
     });
     let config = OpenClawConfig::from_dict(config_json).unwrap();
     let scanner = PluginScanner;
-    let findings = scanner.scan(&config);
+    let kb = KnowledgeBase::default();
+    let findings = scanner.scan(&config, &kb);
     
     assert!(!findings.is_empty());
 }